@@ -80,7 +80,7 @@ fn analyze_predictable_gk() {
     );
 
     // Simulate this scenario
-    let sim = Simulator::new().seed(42);
+    let mut sim = Simulator::new().seed(42);
     let kicker_best = match best_kick_idx {
         0 => vec![1.0, 0.0, 0.0],
         1 => vec![0.0, 1.0, 0.0],
@@ -0,0 +1,78 @@
+use super::analyze_records;
+use crate::football::penalty::{AnalysisError, PenaltyAnalysis};
+use crate::football::stats::load_pk_stats;
+use std::path::Path;
+
+/// Error for a single file in a batch run.
+pub type BatchError = AnalysisError;
+
+/// Outcome of analyzing every CSV file in a directory.
+#[derive(Debug)]
+pub struct BatchReport {
+    /// Successful analyses keyed by file stem, sorted by name
+    pub results: Vec<(String, PenaltyAnalysis)>,
+    /// Files that could not be analyzed, keyed by file stem
+    pub errors: Vec<(String, BatchError)>,
+}
+
+/// Analyzes one player per `*.csv` file in `dir`.
+///
+/// Each file is loaded with `load_pk_stats` in the format described there.
+/// Files that fail to load or solve are collected in `errors` rather than
+/// aborting the batch; only failing to read the directory itself is fatal.
+pub fn analyze_directory(dir: impl AsRef<Path>) -> Result<BatchReport, std::io::Error> {
+    let mut paths: Vec<_> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "csv"))
+        .collect();
+    paths.sort();
+
+    let mut results = Vec::new();
+    let mut errors = Vec::new();
+
+    for path in paths {
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        match analyze_file(&path) {
+            Ok(analysis) => results.push((name, analysis)),
+            Err(err) => errors.push((name, err)),
+        }
+    }
+
+    Ok(BatchReport { results, errors })
+}
+
+/// Loads and analyzes a single player's stats file.
+fn analyze_file(path: &Path) -> Result<PenaltyAnalysis, BatchError> {
+    analyze_records(&load_pk_stats(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::football::stats::write_records_csv;
+    use crate::test_util::pk_records;
+
+    #[test]
+    fn test_analyze_directory() {
+        let dir = std::env::temp_dir().join(format!("fgt_batch_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        write_records_csv(dir.join("alice.csv"), &pk_records([[58, 93, 95], [83, 44, 83], [93, 90, 60]], 100)).unwrap();
+        write_records_csv(dir.join("bob.csv"), &pk_records([[50, 90, 90], [80, 40, 80], [90, 90, 50]], 100)).unwrap();
+        std::fs::write(dir.join("broken.csv"), "kick_direction,gk_direction\nleft\n").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let report = analyze_directory(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let names: Vec<&str> = report.results.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, ["alice", "bob"]);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].0, "broken");
+        assert!(matches!(report.errors[0].1, BatchError::Stats(_)));
+    }
+}
@@ -0,0 +1,152 @@
+use super::analyze_records;
+use super::simulation::SimpleRng;
+use crate::football::penalty::{AnalysisError, PenaltyAnalysis};
+use crate::football::stats::{check_level, PkRecord};
+use crate::solver::game::GameError;
+
+/// Bootstrap percentile intervals for an equilibrium.
+#[derive(Debug, Clone)]
+pub struct StrategyConfidence {
+    /// Equilibrium of the observed records
+    pub estimate: PenaltyAnalysis,
+    /// Number of resampled record sets that solved successfully
+    pub samples: usize,
+    /// `(lower, upper)` bounds for each kicker strategy component
+    pub kicker_strategy: Vec<(f64, f64)>,
+    /// `(lower, upper)` bounds for each goalkeeper strategy component
+    pub goalkeeper_strategy: Vec<(f64, f64)>,
+    /// `(lower, upper)` bounds for the equilibrium goal probability
+    pub goal_probability: (f64, f64),
+}
+
+/// Estimates how sampling noise in `records` propagates to the equilibrium.
+///
+/// Each resample redraws every cell's goals as Binomial(attempts, rate)
+/// using the observed rate, then rebuilds the matrix and solves it. The
+/// returned bounds are the `(1 - confidence) / 2` and `(1 + confidence) / 2`
+/// percentiles over the resamples; resamples that fail to solve are skipped.
+/// Fails if `confidence` is outside (0, 1) or if no resample solves.
+pub fn strategy_confidence(
+    records: &[PkRecord],
+    resamples: usize,
+    seed: u64,
+    confidence: f64,
+) -> Result<StrategyConfidence, AnalysisError> {
+    check_level("Confidence level", confidence)?;
+    let estimate = analyze_records(records)?;
+    let mut rng = SimpleRng::new(seed);
+
+    let mut kicker_samples = Vec::with_capacity(resamples);
+    let mut gk_samples = Vec::with_capacity(resamples);
+    let mut goal_probabilities = Vec::with_capacity(resamples);
+
+    for _ in 0..resamples {
+        let resampled: Vec<PkRecord> = records
+            .iter()
+            .map(|record| {
+                let rate = record.success_rate();
+                let goals = (0..record.attempts).filter(|_| rng.next_f64() < rate).count() as u32;
+                PkRecord { goals, ..record.clone() }
+            })
+            .collect();
+
+        let Ok(analysis) = analyze_records(&resampled) else {
+            continue;
+        };
+
+        goal_probabilities.push(analysis.goal_probability);
+        kicker_samples.push(analysis.kicker_strategy.iter().map(|(_, p)| *p).collect::<Vec<_>>());
+        gk_samples.push(analysis.goalkeeper_strategy.iter().map(|(_, p)| *p).collect::<Vec<_>>());
+    }
+
+    if goal_probabilities.is_empty() {
+        return Err(GameError::NoSolvedSamples { samples: resamples }.into());
+    }
+
+    let alpha = (1.0 - confidence) / 2.0;
+    let interval = |mut values: Vec<f64>| -> (f64, f64) {
+        values.sort_by(f64::total_cmp);
+        (percentile(&values, alpha), percentile(&values, 1.0 - alpha))
+    };
+    let component_intervals = |samples: &[Vec<f64>], len: usize| -> Vec<(f64, f64)> {
+        (0..len)
+            .map(|k| interval(samples.iter().map(|s| s[k]).collect()))
+            .collect()
+    };
+
+    Ok(StrategyConfidence {
+        samples: goal_probabilities.len(),
+        kicker_strategy: component_intervals(&kicker_samples, estimate.kicker_strategy.len()),
+        goalkeeper_strategy: component_intervals(&gk_samples, estimate.goalkeeper_strategy.len()),
+        goal_probability: interval(goal_probabilities),
+        estimate,
+    })
+}
+
+/// Nearest-rank percentile of sorted values.
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    let index = (q * (sorted.len() - 1) as f64).round() as usize;
+    sorted[index.min(sorted.len() - 1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::football::stats::StatsError;
+    use crate::test_util::pk_records;
+
+    fn records() -> Vec<PkRecord> {
+        pk_records([[29, 46, 47], [41, 22, 41], [46, 45, 30]], 50)
+    }
+
+    #[test]
+    fn test_intervals_bracket_estimate() {
+        let result = strategy_confidence(&records(), 300, 42, 0.9).unwrap();
+
+        assert_eq!(result.samples, 300);
+        let (low, high) = result.goal_probability;
+        assert!(low <= result.estimate.goal_probability && result.estimate.goal_probability <= high);
+        assert!(high - low > 0.0);
+
+        for ((low, high), (_, p)) in result.kicker_strategy.iter().zip(&result.estimate.kicker_strategy) {
+            assert!(low <= p && p <= high, "{} not in [{}, {}]", p, low, high);
+        }
+    }
+
+    #[test]
+    fn test_more_resamples_stabilize_bounds() {
+        // Spread of the upper goal-probability bound across seeds
+        let spread = |resamples: usize| {
+            let bounds: Vec<f64> = (0..6)
+                .map(|seed| strategy_confidence(&records(), resamples, seed, 0.9).unwrap().goal_probability.1)
+                .collect();
+            let max = bounds.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+            let min = bounds.iter().cloned().fold(f64::INFINITY, f64::min);
+            max - min
+        };
+
+        assert!(spread(400) < spread(15));
+    }
+
+    #[test]
+    fn test_invalid_confidence_rejected() {
+        for confidence in [0.0, 1.0, 1.5, -0.2, f64::NAN] {
+            assert!(
+                matches!(
+                    strategy_confidence(&records(), 10, 42, confidence),
+                    Err(AnalysisError::Stats(StatsError::InvalidData(_)))
+                ),
+                "{}",
+                confidence
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_solved_resamples_is_an_error() {
+        assert!(matches!(
+            strategy_confidence(&records(), 0, 42, 0.9),
+            Err(AnalysisError::Game(GameError::NoSolvedSamples { samples: 0 }))
+        ));
+    }
+}
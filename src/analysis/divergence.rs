@@ -0,0 +1,37 @@
+/// Kullback-Leibler divergence KL(actual ‖ optimal) in bits.
+///
+/// Measures how far a player's habitual mix `actual` is from `optimal`:
+/// 0 when they match, growing as `actual` leans on actions `optimal`
+/// plays rarely. Terms with zero `actual` probability contribute nothing
+/// (0 · log 0 = 0). Putting weight on an action `optimal` never plays
+/// gives infinity. Entries beyond the shorter slice are ignored.
+pub fn strategy_kl(actual: &[f64], optimal: &[f64]) -> f64 {
+    actual
+        .iter()
+        .zip(optimal)
+        .filter(|(p, _)| **p > 0.0)
+        .map(|(p, q)| if *q > 0.0 { p * (p / q).log2() } else { f64::INFINITY })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_identical_distributions() {
+        let strategy = [0.34, 0.28, 0.38];
+        assert_relative_eq!(strategy_kl(&strategy, &strategy), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_pure_versus_mixed() {
+        // Always kicking left against a uniform optimum: log2(3)
+        let kl = strategy_kl(&[1.0, 0.0, 0.0], &[1.0 / 3.0; 3]);
+        assert!(kl > 0.0);
+        assert_relative_eq!(kl, 3f64.log2(), epsilon = 1e-12);
+
+        assert_eq!(strategy_kl(&[0.5, 0.5, 0.0], &[1.0, 0.0, 0.0]), f64::INFINITY);
+    }
+}
@@ -0,0 +1,116 @@
+use crate::football::penalty::{AnalysisError, Direction, PenaltyAnalysis, PenaltyKick};
+
+/// Equilibrium tracker fed one kick at a time, e.g. during a live match.
+///
+/// Keeps running goal and attempt counts for each kick/dive cell and
+/// re-solves only when asked. Success rates use Laplace smoothing,
+/// `(goals + 1) / (attempts + 2)`, so cells with few or no kicks start
+/// at 0.5 instead of jumping between 0 and 1.
+#[derive(Debug, Clone)]
+pub struct LiveAnalyzer {
+    goals: [[u32; 3]; 3],
+    attempts: [[u32; 3]; 3],
+}
+
+impl LiveAnalyzer {
+    /// Creates a tracker with no kicks recorded.
+    pub fn new() -> Self {
+        Self {
+            goals: [[0; 3]; 3],
+            attempts: [[0; 3]; 3],
+        }
+    }
+
+    /// Adds one kick to the counts.
+    pub fn record_kick(&mut self, kick: Direction, gk: Direction, goal: bool) {
+        let (i, j) = (kick.index(), gk.index());
+        self.attempts[i][j] += 1;
+        if goal {
+            self.goals[i][j] += 1;
+        }
+    }
+
+    /// Number of kicks recorded so far.
+    pub fn total_kicks(&self) -> u32 {
+        self.attempts.iter().flatten().sum()
+    }
+
+    /// Laplace-smoothed success rate matrix, rows kick and columns dive.
+    pub fn success_rates(&self) -> Vec<Vec<f64>> {
+        self.goals
+            .iter()
+            .zip(&self.attempts)
+            .map(|(goals, attempts)| {
+                goals
+                    .iter()
+                    .zip(attempts)
+                    .map(|(&g, &n)| (g as f64 + 1.0) / (n as f64 + 2.0))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Solves the game on the current smoothed success rates.
+    pub fn current_analysis(&self) -> Result<PenaltyAnalysis, AnalysisError> {
+        Ok(PenaltyKick::new(self.success_rates())?.analyze()?)
+    }
+}
+
+impl Default for LiveAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::simulation::SimpleRng;
+
+    /// Largest absolute difference between two matrices.
+    fn max_error(a: &[Vec<f64>], b: &[Vec<f64>]) -> f64 {
+        a.iter()
+            .flatten()
+            .zip(b.iter().flatten())
+            .map(|(x, y)| (x - y).abs())
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_empty_tracker_is_uniform() {
+        let live = LiveAnalyzer::new();
+        assert_eq!(live.total_kicks(), 0);
+        assert_eq!(live.success_rates(), vec![vec![0.5; 3]; 3]);
+        assert!(live.current_analysis().is_ok());
+    }
+
+    #[test]
+    fn test_converges_to_true_matrix() {
+        let pk = PenaltyKick::with_default_data();
+        let truth = pk.payoff_matrix().matrix().clone();
+        let target = pk.analyze().unwrap();
+        let mut rng = SimpleRng::new(42);
+        let mut live = LiveAnalyzer::new();
+
+        let mut feed = |live: &mut LiveAnalyzer, rounds: usize| {
+            for _ in 0..rounds {
+                for (i, row) in truth.iter().enumerate() {
+                    for (j, rate) in row.iter().enumerate() {
+                        let goal = rng.next_f64() < *rate;
+                        live.record_kick(Direction::from_index(i).unwrap(), Direction::from_index(j).unwrap(), goal);
+                    }
+                }
+            }
+        };
+
+        feed(&mut live, 10);
+        let early_error = max_error(&live.success_rates(), &truth);
+        feed(&mut live, 4990);
+        let late_error = max_error(&live.success_rates(), &truth);
+
+        assert_eq!(live.total_kicks(), 45_000);
+        assert!(late_error < early_error);
+        assert!(late_error < 0.02, "late error {}", late_error);
+        assert!(live.current_analysis().unwrap().approx_eq(&target, 0.05));
+    }
+}
@@ -1,2 +1,43 @@
+pub mod batch;
+pub mod bootstrap;
+pub mod divergence;
+pub mod live;
+pub mod power;
+pub mod report;
+pub mod selfplay;
+pub mod sequential;
 pub mod sensitivity;
+pub mod shootout;
 pub mod simulation;
+
+use crate::football::penalty::{AnalysisError, PenaltyAnalysis, PenaltyKick};
+use crate::football::stats::PkRecord;
+
+/// Builds a model from raw kick records and solves it.
+///
+/// Chains `records_to_matrix`, `PenaltyKick::new` and `analyze`.
+pub fn analyze_records(records: &[PkRecord]) -> Result<PenaltyAnalysis, AnalysisError> {
+    Ok(PenaltyKick::from_records(records)?.analyze()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{pk_record, pk_records};
+
+    #[test]
+    fn test_analyze_records() {
+        let records = pk_records([[12, 19, 19], [17, 9, 17], [19, 18, 12]], 20);
+
+        let analysis = analyze_records(&records).unwrap();
+
+        let kicker_sum: f64 = analysis.kicker_strategy.iter().map(|(_, p)| p).sum();
+        assert!((kicker_sum - 1.0).abs() < 1e-9);
+        assert!(analysis.goal_probability > 0.6 && analysis.goal_probability < 0.95);
+
+        assert!(matches!(
+            analyze_records(&[pk_record("left", "nowhere", 1, 20)]),
+            Err(AnalysisError::Stats(_))
+        ));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::football::stats::{check_level, normal_quantile, StatsError};
+
+/// Kicks per strategy needed to tell goal rates `p1` and `p2` apart.
+///
+/// Uses the two-sided two-proportion z-test sample size
+///
+/// `n = (z_{1-alpha/2} * sqrt(2 * pbar * (1 - pbar)) + z_{power} * sqrt(p1 * q1 + p2 * q2))^2 / (p1 - p2)^2`
+///
+/// where `pbar` is the mean of the two rates, rounded up to a whole kick.
+/// Returns `usize::MAX` when the rates are equal, since no finite number
+/// of kicks separates them. Fails unless `alpha` and `power` lie in (0, 1).
+pub fn required_sample_size(p1: f64, p2: f64, alpha: f64, power: f64) -> Result<usize, StatsError> {
+    check_level("Significance level", alpha)?;
+    check_level("Power", power)?;
+
+    let difference = (p1 - p2).abs();
+    if difference == 0.0 {
+        return Ok(usize::MAX);
+    }
+
+    let z_alpha = normal_quantile(1.0 - alpha / 2.0)?;
+    let z_beta = normal_quantile(power)?;
+    let pooled = (p1 + p2) / 2.0;
+
+    let numerator = z_alpha * (2.0 * pooled * (1.0 - pooled)).sqrt()
+        + z_beta * (p1 * (1.0 - p1) + p2 * (1.0 - p2)).sqrt();
+
+    Ok((numerator / difference).powi(2).ceil() as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hand_computed_sample_size() {
+        // (1.95996 * sqrt(0.375) + 0.84162 * sqrt(0.37))^2 / 0.01 = 293.15
+        assert_eq!(required_sample_size(0.8, 0.7, 0.05, 0.8).unwrap(), 294);
+        assert_eq!(required_sample_size(0.7, 0.8, 0.05, 0.8).unwrap(), 294);
+    }
+
+    #[test]
+    fn test_smaller_gaps_need_more_kicks() {
+        assert!(required_sample_size(0.75, 0.7, 0.05, 0.8).unwrap() > required_sample_size(0.8, 0.7, 0.05, 0.8).unwrap());
+        assert!(required_sample_size(0.8, 0.7, 0.05, 0.9).unwrap() > required_sample_size(0.8, 0.7, 0.05, 0.8).unwrap());
+        assert_eq!(required_sample_size(0.7, 0.7, 0.05, 0.8).unwrap(), usize::MAX);
+    }
+
+    #[test]
+    fn test_invalid_levels_rejected() {
+        assert!(required_sample_size(0.8, 0.7, 0.0, 0.8).is_err());
+        assert!(required_sample_size(0.8, 0.7, 1.0, 0.8).is_err());
+        assert!(required_sample_size(0.8, 0.7, 0.05, 1.0).is_err());
+        assert!(required_sample_size(0.8, 0.7, 0.05, f64::NAN).is_err());
+        assert!(required_sample_size(0.7, 0.7, 0.05, -0.5).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::football::penalty::{Direction, PenaltyAnalysis, SUPPORT_THRESHOLD};
+
+/// Renders a penalty analysis as a Markdown report.
+///
+/// Includes the success rate matrix as a table, each player's equilibrium
+/// strategy, the equilibrium goal probability and the strategy supports.
+pub fn markdown_report(analysis: &PenaltyAnalysis) -> String {
+    let payoff = &analysis.payoff_matrix;
+    let mut output = String::from("# Penalty Kick Analysis\n\n## Success Rates\n\n");
+
+    // Table header and alignment row
+    output.push_str("| |");
+    for label in payoff.col_labels() {
+        output.push_str(&format!(" {} |", label));
+    }
+    output.push_str("\n|---|");
+    for _ in payoff.col_labels() {
+        output.push_str("---:|");
+    }
+    output.push('\n');
+
+    for (label, row) in payoff.row_labels().iter().zip(payoff.matrix()) {
+        output.push_str(&format!("| **{}** |", label));
+        for value in row {
+            output.push_str(&format!(" {:.1}% |", value * 100.0));
+        }
+        output.push('\n');
+    }
+
+    output.push_str("\n## Kicker Strategy\n\n");
+    output.push_str(&strategy_list(&analysis.kicker_strategy));
+    output.push_str("\n## Goalkeeper Strategy\n\n");
+    output.push_str(&strategy_list(&analysis.goalkeeper_strategy));
+
+    output.push_str(&format!(
+        "\n**Equilibrium goal probability:** {:.1}%\n\n",
+        analysis.goal_probability * 100.0
+    ));
+    output.push_str(&format!(
+        "- Kicker support: {}\n- Goalkeeper support: {}\n",
+        direction_names(&analysis.kicker_support(SUPPORT_THRESHOLD)),
+        direction_names(&analysis.goalkeeper_support(SUPPORT_THRESHOLD))
+    ));
+
+    output
+}
+
+fn strategy_list(strategy: &[(Direction, f64)]) -> String {
+    strategy
+        .iter()
+        .map(|(dir, prob)| format!("- {}: {:.1}%\n", dir.name(), prob * 100.0))
+        .collect()
+}
+
+fn direction_names(directions: &[Direction]) -> String {
+    directions.iter().map(|d| d.name()).collect::<Vec<_>>().join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::football::penalty::PenaltyKick;
+
+    #[test]
+    fn test_markdown_report() {
+        let analysis = PenaltyKick::with_default_data().analyze().unwrap();
+
+        let report = markdown_report(&analysis);
+
+        assert!(report.contains("| | GK Left | GK Center | GK Right |"));
+        assert!(report.contains("| **Kick Center** | 83.0% | 44.0% | 83.0% |"));
+        assert!(report.contains(&format!(
+            "**Equilibrium goal probability:** {:.1}%",
+            analysis.goal_probability * 100.0
+        )));
+        assert!(report.contains("- Kicker support: Left, Center, Right"));
+    }
+}
@@ -0,0 +1,118 @@
+use super::simulation::{sample_index, SimpleRng};
+use crate::solver::game::{GameError, GameSolver};
+use crate::solver::nash::regret_matching;
+
+/// Strategy trajectories from a self-play run.
+#[derive(Debug, Clone)]
+pub struct SelfPlayResult {
+    /// Kicker's mixed strategy at the start of each round
+    pub kicker_trajectory: Vec<Vec<f64>>,
+    /// Keeper's mixed strategy at the start of each round
+    pub keeper_trajectory: Vec<Vec<f64>>,
+    /// Kicker's strategy averaged over all rounds
+    pub average_kicker: Vec<f64>,
+    /// Keeper's strategy averaged over all rounds
+    pub average_keeper: Vec<f64>,
+}
+
+/// Repeated game in which kicker and keeper both learn by regret matching.
+///
+/// Each round both players sample an action from their current strategy.
+/// Having seen the opponent's action, each player adds to the regret of every
+/// alternative how much better it would have done, then moves its strategy a
+/// fraction `learning_rate` toward playing in proportion to positive
+/// cumulative regret. The round-by-round strategies may cycle, but their
+/// averages approach the Nash equilibrium.
+pub struct SelfPlay;
+
+impl SelfPlay {
+    /// Plays `rounds` rounds on a success-rate matrix.
+    ///
+    /// The kicker maximizes and the keeper minimizes `matrix[kick][dive]`.
+    /// A `learning_rate` of 1 is plain regret matching; smaller values
+    /// smooth the trajectories.
+    pub fn run(
+        matrix: &[Vec<f64>],
+        rounds: usize,
+        learning_rate: f64,
+        seed: u64,
+    ) -> Result<SelfPlayResult, GameError> {
+        // Validates the shape
+        GameSolver::new(matrix.to_vec())?;
+
+        let num_kicks = matrix.len();
+        let num_dives = matrix[0].len();
+        let learning_rate = learning_rate.clamp(0.0, 1.0);
+        let mut rng = SimpleRng::new(seed);
+
+        let mut kicker = vec![1.0 / num_kicks as f64; num_kicks];
+        let mut keeper = vec![1.0 / num_dives as f64; num_dives];
+        let mut kicker_regret = vec![0.0; num_kicks];
+        let mut keeper_regret = vec![0.0; num_dives];
+        let mut average_kicker = vec![0.0; num_kicks];
+        let mut average_keeper = vec![0.0; num_dives];
+        let mut kicker_trajectory = Vec::with_capacity(rounds);
+        let mut keeper_trajectory = Vec::with_capacity(rounds);
+
+        for _ in 0..rounds {
+            for (avg, p) in average_kicker.iter_mut().zip(&kicker) {
+                *avg += p;
+            }
+            for (avg, q) in average_keeper.iter_mut().zip(&keeper) {
+                *avg += q;
+            }
+            kicker_trajectory.push(kicker.clone());
+            keeper_trajectory.push(keeper.clone());
+
+            let kick = sample_index(&mut rng, &kicker);
+            let dive = sample_index(&mut rng, &keeper);
+            let outcome = matrix[kick][dive];
+
+            for (i, regret) in kicker_regret.iter_mut().enumerate() {
+                *regret += matrix[i][dive] - outcome;
+            }
+            for (j, regret) in keeper_regret.iter_mut().enumerate() {
+                *regret += outcome - matrix[kick][j];
+            }
+
+            move_toward(&mut kicker, &regret_matching(&kicker_regret), learning_rate);
+            move_toward(&mut keeper, &regret_matching(&keeper_regret), learning_rate);
+        }
+
+        let rounds = rounds.max(1) as f64;
+        Ok(SelfPlayResult {
+            kicker_trajectory,
+            keeper_trajectory,
+            average_kicker: average_kicker.iter().map(|s| s / rounds).collect(),
+            average_keeper: average_keeper.iter().map(|s| s / rounds).collect(),
+        })
+    }
+}
+
+fn move_toward(current: &mut [f64], target: &[f64], rate: f64) {
+    for (c, &t) in current.iter_mut().zip(target) {
+        *c += rate * (t - *c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::football::penalty::PenaltyKick;
+
+    #[test]
+    fn test_selfplay_approaches_nash() {
+        let matrix = PenaltyKick::with_default_data().payoff_matrix().matrix().clone();
+        let nash = GameSolver::new(matrix.clone()).unwrap().solve().unwrap();
+
+        let result = SelfPlay::run(&matrix, 50_000, 1.0, 42).unwrap();
+
+        assert_eq!(result.kicker_trajectory.len(), 50_000);
+        for (avg, eq) in result.average_kicker.iter().zip(&nash.row_strategy) {
+            assert!((avg - eq).abs() < 0.03, "kicker {:?} vs {:?}", result.average_kicker, nash.row_strategy);
+        }
+        for (avg, eq) in result.average_keeper.iter().zip(&nash.col_strategy) {
+            assert!((avg - eq).abs() < 0.03, "keeper {:?} vs {:?}", result.average_keeper, nash.col_strategy);
+        }
+    }
+}
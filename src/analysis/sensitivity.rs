@@ -1,5 +1,9 @@
-use crate::football::penalty::PenaltyKick;
+use super::simulation::SimpleRng;
+use crate::football::penalty::{LabeledAnalysis, PenaltyKick};
 use crate::solver::game::GameError;
+use std::sync::OnceLock;
+#[cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// Result of a sensitivity analysis.
 #[derive(Debug, Clone)]
@@ -16,17 +20,49 @@ pub struct SensitivityResult {
     pub goalkeeper_strategy_change: Vec<f64>,
     /// Change in equilibrium goal probability
     pub goal_probability_change: f64,
+    /// Relative change in goal probability per relative change in the varied value
+    ///
+    /// Zero when the original value is 0 or the value did not change.
+    pub elasticity: f64,
+}
+
+/// Summary of equilibria over randomly perturbed matrices.
+#[derive(Debug, Clone)]
+pub struct MonteCarloSensitivity {
+    /// Number of perturbed matrices that solved successfully
+    pub samples: usize,
+    /// Mean equilibrium goal probability
+    pub goal_probability_mean: f64,
+    /// Variance of the equilibrium goal probability
+    pub goal_probability_variance: f64,
+    /// Variance of each kicker strategy component
+    pub kicker_strategy_variance: Vec<f64>,
+    /// Variance of each goalkeeper strategy component
+    pub goalkeeper_strategy_variance: Vec<f64>,
 }
 
 /// Performs sensitivity analysis on PK payoff matrices.
+///
+/// Matrices of any shape are supported; results are reported per
+/// `(row, col)` cell.
 pub struct SensitivityAnalyzer {
     base_matrix: Vec<Vec<f64>>,
+    /// Equilibrium of `base_matrix`, solved on first use
+    base_analysis: OnceLock<LabeledAnalysis>,
+    /// Number of times the base matrix has been solved
+    #[cfg(test)]
+    base_solves: AtomicUsize,
 }
 
 impl SensitivityAnalyzer {
     /// Creates a new analyzer with the given base success rate matrix.
     pub fn new(base_matrix: Vec<Vec<f64>>) -> Self {
-        Self { base_matrix }
+        Self {
+            base_matrix,
+            base_analysis: OnceLock::new(),
+            #[cfg(test)]
+            base_solves: AtomicUsize::new(0),
+        }
     }
 
     /// Creates an analyzer with default PK data.
@@ -51,21 +87,105 @@ impl SensitivityAnalyzer {
         col: usize,
         delta: f64,
     ) -> Result<SensitivityResult, GameError> {
-        // Get base solution
-        let base_pk = PenaltyKick::new(self.base_matrix.clone())
-            .map_err(|_| GameError::EmptyMatrix)?;
-        let base_analysis = base_pk.analyze()?;
-
         // Create modified matrix
         let mut modified = self.base_matrix.clone();
         let original_value = modified[row][col];
         modified[row][col] = (modified[row][col] + delta).clamp(0.0, 1.0);
         let new_value = modified[row][col];
 
+        self.compare_with(
+            modified,
+            format!("Success rate [{},{}]", row, col),
+            original_value,
+            new_value,
+        )
+    }
+
+    /// Analyzes the combined effect of changing several success rates at once.
+    ///
+    /// Every cell in `cells` is shifted by `delta` (clamped to [0, 1]). The
+    /// result's `original_value` and `new_value` are averages over the cells.
+    pub fn analyze_joint_change(
+        &self,
+        cells: &[(usize, usize)],
+        delta: f64,
+    ) -> Result<SensitivityResult, GameError> {
+        if cells.is_empty() {
+            return Err(GameError::EmptyMatrix);
+        }
+
+        let mut modified = self.base_matrix.clone();
+        let mut original_sum = 0.0;
+        let mut new_sum = 0.0;
+
+        for &(row, col) in cells {
+            original_sum += self.base_matrix[row][col];
+            modified[row][col] = (self.base_matrix[row][col] + delta).clamp(0.0, 1.0);
+            new_sum += modified[row][col];
+        }
+
+        let parameter = format!(
+            "Success rates {}",
+            cells
+                .iter()
+                .map(|(row, col)| format!("[{},{}]", row, col))
+                .collect::<Vec<_>>()
+                .join(" + ")
+        );
+        let count = cells.len() as f64;
+
+        self.compare_with(modified, parameter, original_sum / count, new_sum / count)
+    }
+
+    /// Measures the interaction between two cells on the goal probability.
+    ///
+    /// Returns the joint change minus the sum of the individual changes.
+    /// A positive value means the two changes reinforce each other (synergy),
+    /// a negative value means they partly cancel out.
+    pub fn pairwise_interaction(
+        &self,
+        a: (usize, usize),
+        b: (usize, usize),
+        delta: f64,
+    ) -> Result<f64, GameError> {
+        let joint = self.analyze_joint_change(&[a, b], delta)?;
+        let single_a = self.analyze_single_change(a.0, a.1, delta)?;
+        let single_b = self.analyze_single_change(b.0, b.1, delta)?;
+
+        Ok(joint.goal_probability_change
+            - single_a.goal_probability_change
+            - single_b.goal_probability_change)
+    }
+
+    /// Returns the base equilibrium, solving it on the first call.
+    fn base_analysis(&self) -> Result<&LabeledAnalysis, GameError> {
+        if let Some(analysis) = self.base_analysis.get() {
+            return Ok(analysis);
+        }
+
+        #[cfg(test)]
+        self.base_solves.fetch_add(1, Ordering::Relaxed);
+
+        let analysis = PenaltyKick::new(self.base_matrix.clone())
+            .map_err(|_| GameError::EmptyMatrix)?
+            .analyze_labeled()?;
+        Ok(self.base_analysis.get_or_init(|| analysis))
+    }
+
+    /// Solves the base and modified matrices and reports the differences.
+    fn compare_with(
+        &self,
+        modified: Vec<Vec<f64>>,
+        parameter: String,
+        original_value: f64,
+        new_value: f64,
+    ) -> Result<SensitivityResult, GameError> {
+        let base_analysis = self.base_analysis()?;
+
         // Get modified solution
         let modified_pk = PenaltyKick::new(modified)
             .map_err(|_| GameError::EmptyMatrix)?;
-        let modified_analysis = modified_pk.analyze()?;
+        let modified_analysis = modified_pk.analyze_labeled()?;
 
         // Calculate changes
         let kicker_strategy_change: Vec<f64> = base_analysis
@@ -85,25 +205,39 @@ impl SensitivityAnalyzer {
         let goal_probability_change =
             modified_analysis.goal_probability - base_analysis.goal_probability;
 
+        let value_change = new_value - original_value;
+        let elasticity = if original_value.abs() < 1e-12
+            || value_change.abs() < 1e-12
+            || base_analysis.goal_probability.abs() < 1e-12
+        {
+            0.0
+        } else {
+            (goal_probability_change / base_analysis.goal_probability)
+                / (value_change / original_value)
+        };
+
         Ok(SensitivityResult {
-            parameter: format!("Success rate [{},{}]", row, col),
+            parameter,
             original_value,
             new_value,
             kicker_strategy_change,
             goalkeeper_strategy_change,
             goal_probability_change,
+            elasticity,
         })
     }
 
     /// Performs a full sensitivity analysis by varying each parameter.
     ///
+    /// Results are in row-major order over the base matrix.
+    ///
     /// # Arguments
     /// * `delta` - Amount to change each success rate
     pub fn full_analysis(&self, delta: f64) -> Result<Vec<SensitivityResult>, GameError> {
         let mut results = Vec::new();
 
-        for row in 0..3 {
-            for col in 0..3 {
+        for row in 0..self.base_matrix.len() {
+            for col in 0..self.num_cols() {
                 let result = self.analyze_single_change(row, col, delta)?;
                 results.push(result);
             }
@@ -112,16 +246,129 @@ impl SensitivityAnalyzer {
         Ok(results)
     }
 
+    /// Computes the derivative of the equilibrium goal probability with respect to each cell.
+    ///
+    /// Uses central differences `(f(x + eps) - f(x - eps)) / (2 * eps)`,
+    /// falling back to a one-sided step where a cell sits within `epsilon`
+    /// of 0 or 1. By the envelope theorem each entry approximates
+    /// `p_i * q_j`, so cells outside either player's support have a
+    /// near-zero gradient. Fails unless `epsilon` is positive and finite.
+    pub fn goal_probability_gradient(&self, epsilon: f64) -> Result<Vec<Vec<f64>>, GameError> {
+        if !(epsilon.is_finite() && epsilon > 0.0) {
+            return Err(GameError::InvalidStep(epsilon));
+        }
+        let num_cols = self.num_cols();
+        if num_cols == 0 {
+            return Err(GameError::EmptyMatrix);
+        }
+        let mut gradient = vec![vec![0.0; num_cols]; self.base_matrix.len()];
+
+        for (row, gradient_row) in gradient.iter_mut().enumerate() {
+            for (col, cell) in gradient_row.iter_mut().enumerate() {
+                let value = self.base_matrix[row][col];
+                let up = (value + epsilon).min(1.0);
+                let down = (value - epsilon).max(0.0);
+
+                let mut upper = self.base_matrix.clone();
+                upper[row][col] = up;
+                let mut lower = self.base_matrix.clone();
+                lower[row][col] = down;
+
+                *cell = (equilibrium_goal_probability(upper)? - equilibrium_goal_probability(lower)?)
+                    / (up - down);
+            }
+        }
+
+        Ok(gradient)
+    }
+
+    /// Measures how robust the equilibrium is to noise in every cell.
+    ///
+    /// Draws `samples` matrices with independent Gaussian noise of standard
+    /// deviation `noise_std` added to each cell (clamped to [0, 1]), solves
+    /// each, and summarizes the spread of the results. Matrices that fail to
+    /// solve are skipped; if none solves, `GameError::NoSolvedSamples` is
+    /// returned.
+    pub fn monte_carlo(
+        &self,
+        noise_std: f64,
+        samples: usize,
+        seed: u64,
+    ) -> Result<MonteCarloSensitivity, GameError> {
+        let mut rng = SimpleRng::new(seed);
+        let mut goal_probabilities = Vec::with_capacity(samples);
+        let mut kicker_strategies = Vec::with_capacity(samples);
+        let mut gk_strategies = Vec::with_capacity(samples);
+
+        for _ in 0..samples {
+            let perturbed: Vec<Vec<f64>> = self
+                .base_matrix
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|&v| (v + noise_std * rng.next_gaussian()).clamp(0.0, 1.0))
+                        .collect()
+                })
+                .collect();
+
+            let Ok(pk) = PenaltyKick::new(perturbed) else {
+                continue;
+            };
+            let Ok(analysis) = pk.analyze_labeled() else {
+                continue;
+            };
+
+            goal_probabilities.push(analysis.goal_probability);
+            kicker_strategies.push(analysis.kicker_strategy.iter().map(|(_, p)| *p).collect::<Vec<_>>());
+            gk_strategies.push(analysis.goalkeeper_strategy.iter().map(|(_, p)| *p).collect::<Vec<_>>());
+        }
+
+        if goal_probabilities.is_empty() {
+            return Err(GameError::NoSolvedSamples { samples });
+        }
+
+        let (goal_probability_mean, goal_probability_variance) = mean_and_variance(&goal_probabilities);
+
+        Ok(MonteCarloSensitivity {
+            samples: goal_probabilities.len(),
+            goal_probability_mean,
+            goal_probability_variance,
+            kicker_strategy_variance: component_variance(&kicker_strategies),
+            goalkeeper_strategy_variance: component_variance(&gk_strategies),
+        })
+    }
+
+    /// Ranks cells by the magnitude of their goal probability elasticity.
+    ///
+    /// Unlike `find_critical_parameters`, this normalizes for the base value
+    /// of each cell, so cells with very different success rates compare fairly.
+    pub fn rank_by_elasticity(&self, delta: f64) -> Result<Vec<(usize, usize, f64)>, GameError> {
+        let results = self.full_analysis(delta)?;
+
+        let num_cols = self.num_cols();
+
+        let mut ranked: Vec<(usize, usize, f64)> = results
+            .iter()
+            .enumerate()
+            .map(|(idx, result)| (idx / num_cols, idx % num_cols, result.elasticity))
+            .collect();
+
+        ranked.sort_by(|a, b| b.2.abs().total_cmp(&a.2.abs()));
+
+        Ok(ranked)
+    }
+
     /// Finds which parameters the optimal strategy is most sensitive to.
     pub fn find_critical_parameters(&self, delta: f64) -> Result<Vec<(usize, usize, f64)>, GameError> {
         let results = self.full_analysis(delta)?;
+        let num_cols = self.num_cols();
 
         let mut critical: Vec<(usize, usize, f64)> = results
             .iter()
             .enumerate()
             .map(|(idx, result)| {
-                let row = idx / 3;
-                let col = idx % 3;
+                let row = idx / num_cols;
+                let col = idx % num_cols;
                 let total_change: f64 = result
                     .kicker_strategy_change
                     .iter()
@@ -132,10 +379,40 @@ impl SensitivityAnalyzer {
             })
             .collect();
 
-        critical.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap());
+        critical.sort_by(|a, b| b.2.total_cmp(&a.2));
 
         Ok(critical)
     }
+
+    /// Number of columns in the base matrix.
+    fn num_cols(&self) -> usize {
+        self.base_matrix.first().map_or(0, Vec::len)
+    }
+}
+
+/// Returns the mean and (population) variance of the values.
+fn mean_and_variance(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    (mean, variance)
+}
+
+/// Returns the variance of each component across a set of strategies.
+fn component_variance(strategies: &[Vec<f64>]) -> Vec<f64> {
+    let len = strategies.first().map_or(0, Vec::len);
+    (0..len)
+        .map(|k| {
+            let column: Vec<f64> = strategies.iter().map(|s| s[k]).collect();
+            mean_and_variance(&column).1
+        })
+        .collect()
+}
+
+/// Solves a success rate matrix and returns its equilibrium goal probability.
+fn equilibrium_goal_probability(matrix: Vec<Vec<f64>>) -> Result<f64, GameError> {
+    let pk = PenaltyKick::new(matrix).map_err(|_| GameError::EmptyMatrix)?;
+    Ok(pk.analyze_labeled()?.goal_probability)
 }
 
 #[cfg(test)]
@@ -158,4 +435,138 @@ mod tests {
 
         assert_eq!(results.len(), 9); // 3x3 matrix
     }
+
+    #[test]
+    fn test_base_matrix_solved_once() {
+        let analyzer = SensitivityAnalyzer::with_default_data();
+
+        analyzer.full_analysis(0.05).unwrap();
+        assert_eq!(analyzer.base_solves.load(Ordering::Relaxed), 1);
+
+        analyzer.full_analysis(0.1).unwrap();
+        analyzer.analyze_single_change(1, 1, -0.05).unwrap();
+        assert_eq!(analyzer.base_solves.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_goal_probability_gradient() {
+        let analyzer = SensitivityAnalyzer::with_default_data();
+        let gradient = analyzer.goal_probability_gradient(1e-4).unwrap();
+
+        assert_eq!(gradient.len(), 3);
+        assert!(gradient.iter().all(|row| row.len() == 3));
+        // Raising any success rate can only help the kicker
+        assert!(gradient.iter().flatten().all(|&g| g >= -1e-6));
+
+        // Kicking center is strictly dominated, so its row has zero gradient
+        let dominated = SensitivityAnalyzer::new(vec![
+            vec![0.58, 0.93, 0.95],
+            vec![0.30, 0.30, 0.30],
+            vec![0.93, 0.90, 0.60],
+        ]);
+        let gradient = dominated.goal_probability_gradient(1e-4).unwrap();
+        for &g in &gradient[1] {
+            assert!(g.abs() < 1e-6);
+        }
+
+        let empty = SensitivityAnalyzer::new(vec![]);
+        assert!(matches!(empty.goal_probability_gradient(1e-4), Err(GameError::EmptyMatrix)));
+        let no_columns = SensitivityAnalyzer::new(vec![vec![]]);
+        assert!(matches!(no_columns.goal_probability_gradient(1e-4), Err(GameError::EmptyMatrix)));
+    }
+
+    #[test]
+    fn test_gradient_rejects_invalid_step() {
+        let analyzer = SensitivityAnalyzer::new(PenaltyKick::with_default_data().payoff_matrix().matrix().clone());
+
+        for epsilon in [0.0, -1e-4, f64::NAN, f64::INFINITY] {
+            assert!(
+                matches!(analyzer.goal_probability_gradient(epsilon), Err(GameError::InvalidStep(_))),
+                "{}",
+                epsilon
+            );
+        }
+    }
+
+    #[test]
+    fn test_rankings_follow_matrix_shape() {
+        // Two kick options against three dives
+        let analyzer = SensitivityAnalyzer::new(vec![
+            vec![0.58, 0.93, 0.95],
+            vec![0.93, 0.90, 0.60],
+        ]);
+
+        let ranked = analyzer.rank_by_elasticity(0.05).unwrap();
+        let critical = analyzer.find_critical_parameters(0.05).unwrap();
+
+        assert_eq!(ranked.len(), 6);
+        assert_eq!(critical.len(), 6);
+        let mut cells: Vec<(usize, usize)> = ranked.iter().map(|&(row, col, _)| (row, col)).collect();
+        cells.sort();
+        assert_eq!(cells, vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+        for &(row, col, elasticity) in &ranked {
+            let single = analyzer.analyze_single_change(row, col, 0.05).unwrap();
+            assert_eq!(elasticity, single.elasticity);
+        }
+    }
+
+    #[test]
+    fn test_elasticity() {
+        let analyzer = SensitivityAnalyzer::with_default_data();
+        let base_goal = PenaltyKick::with_default_data().analyze().unwrap().goal_probability;
+
+        // Same absolute delta on a low (0.44) and a high (0.83) base rate
+        let low = analyzer.analyze_single_change(1, 1, 0.05).unwrap();
+        let high = analyzer.analyze_single_change(1, 0, 0.05).unwrap();
+
+        for result in [&low, &high] {
+            let expected = (result.goal_probability_change / base_goal)
+                / ((result.new_value - result.original_value) / result.original_value);
+            assert!((result.elasticity - expected).abs() < 1e-12);
+        }
+        assert!((low.elasticity - high.elasticity).abs() > 1e-6);
+
+        let zero = SensitivityAnalyzer::new(vec![
+            vec![0.0, 0.93, 0.95],
+            vec![0.83, 0.44, 0.83],
+            vec![0.93, 0.90, 0.60],
+        ]);
+        assert_eq!(zero.analyze_single_change(0, 0, 0.05).unwrap().elasticity, 0.0);
+    }
+
+    #[test]
+    fn test_monte_carlo_noise_increases_variance() {
+        let analyzer = SensitivityAnalyzer::with_default_data();
+
+        let quiet = analyzer.monte_carlo(0.01, 200, 7).unwrap();
+        let noisy = analyzer.monte_carlo(0.05, 200, 7).unwrap();
+
+        assert_eq!(quiet.samples, 200);
+        assert!(noisy.goal_probability_variance > quiet.goal_probability_variance);
+        assert!(noisy.kicker_strategy_variance[0] > quiet.kicker_strategy_variance[0]);
+        assert!((quiet.goal_probability_mean - 0.78).abs() < 0.05);
+
+        assert!(matches!(
+            analyzer.monte_carlo(0.01, 0, 7),
+            Err(GameError::NoSolvedSamples { samples: 0 })
+        ));
+        let unsolvable = SensitivityAnalyzer::new(vec![vec![f64::NAN; 3]; 3]);
+        assert!(matches!(
+            unsolvable.monte_carlo(0.01, 5, 7),
+            Err(GameError::NoSolvedSamples { samples: 5 })
+        ));
+    }
+
+    #[test]
+    fn test_joint_change_and_interaction() {
+        let analyzer = SensitivityAnalyzer::with_default_data();
+
+        let joint = analyzer.analyze_joint_change(&[(0, 0), (2, 2)], 0.1).unwrap();
+        assert!(joint.parameter.contains("[0,0]") && joint.parameter.contains("[2,2]"));
+        assert!(joint.goal_probability_change > 0.0);
+
+        let interaction = analyzer.pairwise_interaction((0, 0), (2, 2), 0.1).unwrap();
+        assert!(interaction.is_finite());
+        assert!(interaction.abs() > 1e-6);
+    }
 }
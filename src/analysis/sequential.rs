@@ -0,0 +1,102 @@
+use crate::football::penalty::PenaltyKick;
+use crate::solver::game::{GameError, GameSolver};
+
+/// Equilibrium of a `SequentialPK` game.
+#[derive(Debug, Clone)]
+pub struct SequentialSolution {
+    /// Kicker's optimal strategy, fixed before knowing whether the tell shows
+    pub kicker_strategy: Vec<f64>,
+    /// Keeper's dive strategy when no tell is seen
+    pub blind_keeper_strategy: Vec<f64>,
+    /// Keeper's dive against each kick direction when the tell is seen
+    pub informed_dives: Vec<usize>,
+    /// Kicker's expected goal probability
+    pub goal_probability: f64,
+}
+
+/// Two-stage penalty where the keeper may read the kicker's run-up.
+///
+/// The kicker commits to a mixed strategy over directions. With probability
+/// `tell_probability` the run-up gives the direction away and the keeper
+/// dives to the spot with the lowest success rate for that kick; otherwise
+/// the keeper dives without information, as in the simultaneous game.
+///
+/// Because the keeper's informed reply depends only on the kick, the game
+/// reduces to a matrix game with entries `(1 - q) * a_ij + q * min_k a_ik`,
+/// whose rows the kicker plays against the blind keeper's columns.
+#[derive(Debug)]
+pub struct SequentialPK {
+    pk: PenaltyKick,
+    tell_probability: f64,
+}
+
+impl SequentialPK {
+    /// Creates the game for `pk`, clamping `tell_probability` to [0, 1].
+    pub fn new(pk: PenaltyKick, tell_probability: f64) -> Self {
+        Self {
+            pk,
+            tell_probability: tell_probability.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Returns the probability that the keeper sees the tell.
+    pub fn tell_probability(&self) -> f64 {
+        self.tell_probability
+    }
+
+    /// Solves for the kicker's strategy given the information leakage.
+    pub fn solve(&self) -> Result<SequentialSolution, GameError> {
+        let matrix = self.pk.payoff_matrix().matrix();
+        let q = self.tell_probability;
+
+        // Informed keeper's dive for each kick, ties to the lowest index
+        let informed_dives: Vec<usize> = matrix
+            .iter()
+            .map(|row| {
+                (0..row.len())
+                    .min_by(|&a, &b| row[a].total_cmp(&row[b]))
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let reduced: Vec<Vec<f64>> = matrix
+            .iter()
+            .zip(&informed_dives)
+            .map(|(row, &dive)| row.iter().map(|a| (1.0 - q) * a + q * row[dive]).collect())
+            .collect();
+
+        let solution = GameSolver::new(reduced)?.solve()?;
+
+        Ok(SequentialSolution {
+            kicker_strategy: solution.row_strategy,
+            blind_keeper_strategy: solution.col_strategy,
+            informed_dives,
+            goal_probability: solution.game_value,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_tell_probability_extremes() {
+        let pk = PenaltyKick::with_default_data();
+        let nash = pk.analyze().unwrap();
+
+        let simultaneous = SequentialPK::new(PenaltyKick::with_default_data(), 0.0).solve().unwrap();
+        assert_relative_eq!(simultaneous.goal_probability, nash.goal_probability, epsilon = 1e-9);
+
+        // A fully read kicker can only guarantee the best worst case: 0.60 kicking right
+        let read = SequentialPK::new(PenaltyKick::with_default_data(), 1.0).solve().unwrap();
+        assert_relative_eq!(read.goal_probability, 0.60, epsilon = 1e-9);
+        assert_eq!(read.informed_dives, vec![0, 1, 2]);
+        assert!(read.goal_probability < simultaneous.goal_probability);
+
+        let partial = SequentialPK::new(pk, 0.3).solve().unwrap();
+        assert!(read.goal_probability < partial.goal_probability);
+        assert!(partial.goal_probability < simultaneous.goal_probability);
+    }
+}
@@ -0,0 +1,280 @@
+use super::simulation::{sample_direction, RngSource, SimpleRng};
+use crate::football::payoff::PayoffError;
+use crate::football::penalty::{Direction, PenaltyKick};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ShootoutError {
+    #[error("Team strategy needs at least one shooter")]
+    NoShooters,
+}
+
+/// Number of kicks per team before sudden death.
+const REGULATION_ROUNDS: usize = 5;
+
+/// Safety cap on sudden-death rounds.
+const MAX_SUDDEN_DEATH_ROUNDS: usize = 100;
+
+/// Identifies a team in a shootout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Team {
+    A,
+    B,
+}
+
+/// Strategies supplied by one team.
+#[derive(Debug, Clone)]
+pub struct TeamStrategy {
+    /// Kick direction distribution for each shooter, in kicking order
+    shooters: Vec<Vec<f64>>,
+    /// Dive direction distribution for the team's goalkeeper
+    keeper: Vec<f64>,
+}
+
+impl TeamStrategy {
+    /// Creates a team strategy from shooter and keeper distributions.
+    ///
+    /// Fails if `shooters` is empty, since every round needs a kicker.
+    pub fn new(shooters: Vec<Vec<f64>>, keeper: Vec<f64>) -> Result<Self, ShootoutError> {
+        if shooters.is_empty() {
+            return Err(ShootoutError::NoShooters);
+        }
+        Ok(Self { shooters, keeper })
+    }
+
+    /// Returns the kick direction distributions, in kicking order.
+    pub fn shooters(&self) -> &[Vec<f64>] {
+        &self.shooters
+    }
+
+    /// Returns the goalkeeper's dive direction distribution.
+    pub fn keeper(&self) -> &[f64] {
+        &self.keeper
+    }
+
+    /// Returns the strategy for the shooter taking the team's `n`-th kick.
+    ///
+    /// Shooters are reused in order once the list is exhausted.
+    fn shooter(&self, n: usize) -> &[f64] {
+        &self.shooters[n % self.shooters.len()]
+    }
+}
+
+/// A single kick taken during a shootout.
+#[derive(Debug, Clone, Copy)]
+pub struct ShootoutKick {
+    pub team: Team,
+    pub shooter: usize,
+    pub kick_direction: Direction,
+    pub gk_direction: Direction,
+    pub is_goal: bool,
+}
+
+/// Outcome of one round (one kick per team).
+#[derive(Debug, Clone)]
+pub struct ShootoutRound {
+    /// Round number, starting at 1
+    pub round: usize,
+    /// Team A's kick
+    pub team_a: ShootoutKick,
+    /// Team B's kick, `None` if the shootout was decided before it was taken
+    pub team_b: Option<ShootoutKick>,
+    /// Running score (A, B) after the round
+    pub score: (u32, u32),
+}
+
+/// Result of a full shootout.
+#[derive(Debug, Clone)]
+pub struct ShootoutResult {
+    pub rounds: Vec<ShootoutRound>,
+    /// Winning team, `None` only if the sudden-death cap was reached
+    pub winner: Option<Team>,
+    pub score: (u32, u32),
+    pub kicks_taken: usize,
+}
+
+impl ShootoutResult {
+    /// Returns true if the shootout went beyond the regulation rounds.
+    pub fn went_to_sudden_death(&self) -> bool {
+        self.rounds.len() > REGULATION_ROUNDS
+    }
+}
+
+/// Simulates best-of-5 penalty shootouts with sudden death.
+pub struct Shootout {
+    pk: PenaltyKick,
+}
+
+impl Shootout {
+    /// Creates a shootout simulator with default PK data.
+    pub fn new() -> Self {
+        Self {
+            pk: PenaltyKick::with_default_data(),
+        }
+    }
+
+    /// Creates a shootout simulator with custom success rates.
+    pub fn with_matrix(success_rates: Vec<Vec<f64>>) -> Result<Self, PayoffError> {
+        Ok(Self {
+            pk: PenaltyKick::new(success_rates)?,
+        })
+    }
+
+    /// Simulates a shootout with team A kicking first.
+    ///
+    /// Teams alternate kicks. During the first five rounds the shootout
+    /// stops as soon as one team cannot be caught with its remaining kicks;
+    /// afterwards rounds continue in sudden death until one team leads
+    /// after a completed round.
+    pub fn simulate(&self, team_a: &TeamStrategy, team_b: &TeamStrategy, seed: u64) -> ShootoutResult {
+        let mut rng = SimpleRng::new(seed);
+        let mut rounds = Vec::new();
+        let mut score = (0u32, 0u32);
+        let mut kicks_taken = 0;
+        let mut winner = None;
+
+        for round in 0..REGULATION_ROUNDS + MAX_SUDDEN_DEATH_ROUNDS {
+            let regulation = round < REGULATION_ROUNDS;
+
+            // Team A kicks against team B's keeper
+            let kick_a = self.take_kick(&mut rng, Team::A, round, team_a, team_b);
+            score.0 += kick_a.is_goal as u32;
+            kicks_taken += 1;
+
+            if regulation && let Some(team) = decided_early(score, round + 1, round) {
+                winner = Some(team);
+                rounds.push(ShootoutRound { round: round + 1, team_a: kick_a, team_b: None, score });
+                break;
+            }
+
+            // Team B kicks against team A's keeper
+            let kick_b = self.take_kick(&mut rng, Team::B, round, team_b, team_a);
+            score.1 += kick_b.is_goal as u32;
+            kicks_taken += 1;
+
+            rounds.push(ShootoutRound { round: round + 1, team_a: kick_a, team_b: Some(kick_b), score });
+
+            if regulation {
+                if let Some(team) = decided_early(score, round + 1, round + 1) {
+                    winner = Some(team);
+                    break;
+                }
+            } else if score.0 != score.1 {
+                winner = Some(if score.0 > score.1 { Team::A } else { Team::B });
+                break;
+            }
+        }
+
+        ShootoutResult {
+            rounds,
+            winner,
+            score,
+            kicks_taken,
+        }
+    }
+
+    /// Simulates one kick by `kicking` against the keeper of `defending`.
+    fn take_kick(
+        &self,
+        rng: &mut impl RngSource,
+        team: Team,
+        round: usize,
+        kicking: &TeamStrategy,
+        defending: &TeamStrategy,
+    ) -> ShootoutKick {
+        let matrix = self.pk.payoff_matrix().matrix();
+        let kick_direction = sample_direction(rng, kicking.shooter(round));
+        let gk_direction = sample_direction(rng, &defending.keeper);
+        let is_goal = rng.next_f64() < matrix[kick_direction.index()][gk_direction.index()];
+
+        ShootoutKick {
+            team,
+            shooter: round % kicking.shooters.len(),
+            kick_direction,
+            gk_direction,
+            is_goal,
+        }
+    }
+}
+
+impl Default for Shootout {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Checks whether the regulation phase is mathematically decided.
+///
+/// `taken_a` and `taken_b` are the kicks each team has taken so far.
+fn decided_early(score: (u32, u32), taken_a: usize, taken_b: usize) -> Option<Team> {
+    let remaining_a = (REGULATION_ROUNDS - taken_a) as u32;
+    let remaining_b = (REGULATION_ROUNDS - taken_b) as u32;
+
+    if score.0 > score.1 + remaining_b {
+        Some(Team::A)
+    } else if score.1 > score.0 + remaining_a {
+        Some(Team::B)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Kicking left always scores, kicking right never does.
+    fn scripted_shootout() -> Shootout {
+        Shootout::with_matrix(vec![
+            vec![1.0, 1.0, 1.0],
+            vec![0.5, 0.5, 0.5],
+            vec![0.0, 0.0, 0.0],
+        ])
+        .unwrap()
+    }
+
+    const LEFT: [f64; 3] = [1.0, 0.0, 0.0];
+    const RIGHT: [f64; 3] = [0.0, 0.0, 1.0];
+    const UNIFORM: [f64; 3] = [1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+
+    #[test]
+    fn test_early_termination() {
+        let shootout = scripted_shootout();
+        let team_a = TeamStrategy::new(vec![LEFT.to_vec()], UNIFORM.to_vec()).unwrap();
+        let team_b = TeamStrategy::new(vec![RIGHT.to_vec()], UNIFORM.to_vec()).unwrap();
+
+        let result = shootout.simulate(&team_a, &team_b, 42);
+
+        // After B3 misses it is 3-0 and B's two remaining kicks cannot catch up
+        assert_eq!(result.score, (3, 0));
+        assert_eq!(result.winner, Some(Team::A));
+        assert_eq!(result.kicks_taken, 6);
+        assert_eq!(result.rounds.len(), 3);
+        assert!(!result.went_to_sudden_death());
+    }
+
+    #[test]
+    fn test_sudden_death() {
+        let shootout = scripted_shootout();
+        let team_a = TeamStrategy::new(vec![LEFT.to_vec()], UNIFORM.to_vec()).unwrap();
+        let mut b_shooters = vec![LEFT.to_vec(); 5];
+        b_shooters.push(RIGHT.to_vec());
+        let team_b = TeamStrategy::new(b_shooters, UNIFORM.to_vec()).unwrap();
+
+        let result = shootout.simulate(&team_a, &team_b, 7);
+
+        assert!(result.went_to_sudden_death());
+        assert_eq!(result.score, (6, 5));
+        assert_eq!(result.winner, Some(Team::A));
+        assert_eq!(result.kicks_taken, 12);
+        assert_eq!(result.rounds.len(), 6);
+    }
+
+    #[test]
+    fn test_team_without_shooters_rejected() {
+        assert_eq!(
+            TeamStrategy::new(vec![], UNIFORM.to_vec()).unwrap_err(),
+            ShootoutError::NoShooters
+        );
+    }
+}
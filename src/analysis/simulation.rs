@@ -1,14 +1,32 @@
-use crate::football::penalty::{Direction, PenaltyKick};
+use crate::football::penalty::{AnalysisError, Direction, PenaltyKick};
+use crate::football::stats::{wilson_interval, StatsError};
 use crate::solver::game::GameError;
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
 
 /// Result of a single simulated penalty kick.
+///
+/// Actions are stored as row and column indices into the payoff matrix, so
+/// models with any number of kick or dive options can be simulated.
 #[derive(Debug, Clone, Copy)]
 pub struct SimulatedKick {
-    pub kick_direction: Direction,
-    pub gk_direction: Direction,
+    pub kick_index: usize,
+    pub gk_index: usize,
     pub is_goal: bool,
 }
 
+impl SimulatedKick {
+    /// Returns the kick direction in the standard three-direction model.
+    pub fn kick_direction(&self) -> Option<Direction> {
+        Direction::from_index(self.kick_index)
+    }
+
+    /// Returns the dive direction in the standard three-direction model.
+    pub fn gk_direction(&self) -> Option<Direction> {
+        Direction::from_index(self.gk_index)
+    }
+}
+
 /// Result of a simulation run.
 #[derive(Debug, Clone)]
 pub struct SimulationResult {
@@ -17,6 +35,30 @@ pub struct SimulationResult {
     pub total_kicks: u32,
     pub kicker_strategy: Vec<f64>,
     pub goalkeeper_strategy: Vec<f64>,
+    /// Kicker action labels, indexed by `SimulatedKick::kick_index`
+    pub kick_labels: Vec<String>,
+    /// Goalkeeper action labels, indexed by `SimulatedKick::gk_index`
+    pub gk_labels: Vec<String>,
+}
+
+/// Aggregate simulation statistics in JSON export form.
+#[derive(Serialize)]
+struct SimulationSummary {
+    total_kicks: u32,
+    goals_scored: u32,
+    goal_percentage: f64,
+    kicker_strategy: Vec<f64>,
+    goalkeeper_strategy: Vec<f64>,
+    direction_stats: Vec<DirectionStat>,
+}
+
+/// Goals and attempts for one direction combination in JSON export form.
+#[derive(Serialize)]
+struct DirectionStat {
+    kick_direction: String,
+    gk_direction: String,
+    goals: u32,
+    attempts: u32,
 }
 
 impl SimulationResult {
@@ -29,33 +71,186 @@ impl SimulationResult {
         }
     }
 
+    /// Returns a Wilson score interval for the goal rate (as a fraction).
+    ///
+    /// Fails unless `confidence` lies in (0, 1).
+    pub fn goal_rate_interval(&self, confidence: f64) -> Result<(f64, f64), StatsError> {
+        wilson_interval(self.goals_scored as u64, self.total_kicks as u64, confidence)
+    }
+
+    /// Returns the label of kicker action `index`, if known.
+    pub fn kick_label(&self, index: usize) -> Option<&str> {
+        self.kick_labels.get(index).map(String::as_str)
+    }
+
+    /// Returns the label of goalkeeper action `index`, if known.
+    pub fn gk_label(&self, index: usize) -> Option<&str> {
+        self.gk_labels.get(index).map(String::as_str)
+    }
+
+    /// Returns goals and attempts for each `(kick_index, gk_index)` combination.
+    ///
+    /// Only combinations that occurred are listed, in row-major order.
+    pub fn index_stats(&self) -> Vec<((usize, usize), u32, u32)> {
+        let mut counts: BTreeMap<(usize, usize), (u32, u32)> = BTreeMap::new();
+
+        for kick in &self.kicks {
+            let (goals, attempts) = counts.entry((kick.kick_index, kick.gk_index)).or_default();
+            *goals += kick.is_goal as u32;
+            *attempts += 1;
+        }
+
+        counts
+            .into_iter()
+            .map(|(cell, (goals, attempts))| (cell, goals, attempts))
+            .collect()
+    }
+
     /// Returns statistics for each direction combination.
+    ///
+    /// Only meaningful for the standard 3×3 model; combinations whose indices
+    /// do not map to a `Direction` are skipped.
     pub fn direction_stats(&self) -> Vec<((Direction, Direction), u32, u32)> {
-        let mut stats = vec![];
-
-        for kick_dir in Direction::all() {
-            for gk_dir in Direction::all() {
-                let (goals, attempts) = self.kicks.iter().fold((0, 0), |(g, a), kick| {
-                    if kick.kick_direction == *kick_dir && kick.gk_direction == *gk_dir {
-                        (g + if kick.is_goal { 1 } else { 0 }, a + 1)
-                    } else {
-                        (g, a)
-                    }
-                });
-                if attempts > 0 {
-                    stats.push(((*kick_dir, *gk_dir), goals, attempts));
-                }
+        self.index_stats()
+            .into_iter()
+            .filter_map(|((kick, gk), goals, attempts)| {
+                Some(((Direction::from_index(kick)?, Direction::from_index(gk)?), goals, attempts))
+            })
+            .collect()
+    }
+
+    /// Exports the kick log as CSV.
+    ///
+    /// Emits a header plus one row per kick:
+    /// `index,kick_direction,gk_direction,is_goal`. In the 3×3 model
+    /// directions use the lowercase form accepted by the stats CSV loader;
+    /// other models use the action labels.
+    pub fn to_csv(&self) -> String {
+        let mut output = String::from("index,kick_direction,gk_direction,is_goal\n");
+
+        for (i, kick) in self.kicks.iter().enumerate() {
+            output.push_str(&format!(
+                "{},{},{},{}\n",
+                i,
+                export_name(&self.kick_labels, kick.kick_index),
+                export_name(&self.gk_labels, kick.gk_index),
+                kick.is_goal
+            ));
+        }
+
+        output
+    }
+
+    /// Exports the aggregate statistics and per-combination stats as JSON.
+    pub fn to_json(&self) -> String {
+        let summary = SimulationSummary {
+            total_kicks: self.total_kicks,
+            goals_scored: self.goals_scored,
+            goal_percentage: self.goal_percentage(),
+            kicker_strategy: self.kicker_strategy.clone(),
+            goalkeeper_strategy: self.goalkeeper_strategy.clone(),
+            direction_stats: self
+                .index_stats()
+                .into_iter()
+                .map(|((kick, gk), goals, attempts)| DirectionStat {
+                    kick_direction: export_name(&self.kick_labels, kick),
+                    gk_direction: export_name(&self.gk_labels, gk),
+                    goals,
+                    attempts,
+                })
+                .collect(),
+        };
+
+        serde_json::to_string_pretty(&summary).expect("Simulation summary should serialize")
+    }
+
+    /// Returns the longest run of consecutive goals.
+    pub fn longest_goal_streak(&self) -> usize {
+        self.longest_streak(true)
+    }
+
+    /// Returns the longest run of consecutive misses (saves or off target).
+    pub fn longest_miss_streak(&self) -> usize {
+        self.longest_streak(false)
+    }
+
+    /// Returns the variance of the goal indicator for each direction combination.
+    ///
+    /// Uses the population variance `p * (1 - p)` of the observed goal rate,
+    /// so a cell that always (or never) scores has zero variance.
+    pub fn direction_variance(&self) -> Vec<((Direction, Direction), f64)> {
+        self.direction_stats()
+            .into_iter()
+            .map(|(dirs, goals, attempts)| {
+                let p = goals as f64 / attempts as f64;
+                (dirs, p * (1.0 - p))
+            })
+            .collect()
+    }
+
+    fn longest_streak(&self, goal: bool) -> usize {
+        let mut longest = 0;
+        let mut current = 0;
+
+        for kick in &self.kicks {
+            if kick.is_goal == goal {
+                current += 1;
+                longest = longest.max(current);
+            } else {
+                current = 0;
             }
         }
 
-        stats
+        longest
     }
 }
 
+/// Result of a simulation against an adaptive goalkeeper.
+#[derive(Debug, Clone)]
+pub struct AdaptiveSimulationResult {
+    /// The simulated kicks; `goalkeeper_strategy` holds the empirical dive frequencies
+    pub result: SimulationResult,
+    /// Kicker distribution inferred by the keeper over the final window
+    pub inferred_kicker_strategy: Vec<f64>,
+}
+
+/// Summary of simulating the equilibrium against alternative strategies.
+#[derive(Debug, Clone)]
+pub struct StrategyComparison {
+    /// Goal percentage with both players at equilibrium
+    pub optimal_goal_percentage: f64,
+    /// Goal percentage with the alternative strategies
+    pub alternative_goal_percentage: f64,
+    /// Optimal minus alternative goal percentage, in percentage points
+    pub difference: f64,
+    /// True if the goal rate intervals at the requested confidence don't overlap
+    pub significant: bool,
+    /// Goal percentage of kicks aimed at each direction as `(direction, optimal, alternative)`
+    pub direction_breakdown: Vec<(Direction, f64, f64)>,
+}
+
+/// Source of uniform random numbers for simulations.
+///
+/// Implement this to drive `Simulator` from an external generator.
+pub trait RngSource {
+    /// Returns a uniformly distributed value in `[0, 1)`.
+    fn next_f64(&mut self) -> f64;
+}
+
 /// Simulates penalty kick scenarios.
-pub struct Simulator {
+///
+/// By default every run draws from a fresh built-in stream derived from the
+/// seed. A generator supplied with `with_rng` is used instead by the
+/// sequential runs (`simulate`, `simulate_antithetic` and
+/// `simulate_adaptive_gk`, which therefore take `&mut self`) and advances
+/// across calls.
+pub struct Simulator<R: RngSource = SimpleRng> {
     pk: PenaltyKick,
     rng_seed: u64,
+    exploration_rate: f64,
+    fatigue_decay: f64,
+    fatigue_floor: f64,
+    rng: Option<R>,
 }
 
 impl Simulator {
@@ -64,6 +259,10 @@ impl Simulator {
         Self {
             pk: PenaltyKick::with_default_data(),
             rng_seed: 12345,
+            exploration_rate: 0.1,
+            fatigue_decay: 0.0,
+            fatigue_floor: 0.0,
+            rng: None,
         }
     }
 
@@ -71,16 +270,65 @@ impl Simulator {
     pub fn with_matrix(success_rates: Vec<Vec<f64>>) -> Result<Self, crate::football::payoff::PayoffError> {
         Ok(Self {
             pk: PenaltyKick::new(success_rates)?,
-            rng_seed: 12345,
+            ..Self::new()
         })
     }
 
+    /// Uses `rng` instead of the built-in generator for sequential runs.
+    ///
+    /// `simulate_chunked` and `simulate_parallel` ignore it and reseed each
+    /// chunk from `substream`, since every chunk needs its own independent
+    /// generator.
+    pub fn with_rng<R: RngSource>(self, rng: R) -> Simulator<R> {
+        Simulator {
+            pk: self.pk,
+            rng_seed: self.rng_seed,
+            exploration_rate: self.exploration_rate,
+            fatigue_decay: self.fatigue_decay,
+            fatigue_floor: self.fatigue_floor,
+            rng: Some(rng),
+        }
+    }
+
+    /// Reports whether the goal rate intervals of two runs overlap.
+    ///
+    /// Non-overlapping intervals indicate the difference is significant at
+    /// (at least) the given confidence level.
+    pub fn intervals_overlap(
+        a: &SimulationResult,
+        b: &SimulationResult,
+        confidence: f64,
+    ) -> Result<bool, StatsError> {
+        let (a_low, a_high) = a.goal_rate_interval(confidence)?;
+        let (b_low, b_high) = b.goal_rate_interval(confidence)?;
+        Ok(a_low <= b_high && b_low <= a_high)
+    }
+}
+
+impl<R: RngSource> Simulator<R> {
     /// Sets the random seed for reproducibility.
     pub fn seed(mut self, seed: u64) -> Self {
         self.rng_seed = seed;
         self
     }
 
+    /// Sets how often an adaptive goalkeeper dives at random instead of best-responding.
+    pub fn exploration_rate(mut self, rate: f64) -> Self {
+        self.exploration_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Enables kicker fatigue during `simulate`.
+    ///
+    /// The success rate of kick `n` (0-based) becomes
+    /// `base_rate * (1 - decay_per_kick * n).max(floor)`. Only the matrix
+    /// lookup is affected; the equilibrium solver is untouched.
+    pub fn with_fatigue(mut self, decay_per_kick: f64, floor: f64) -> Self {
+        self.fatigue_decay = decay_per_kick.max(0.0);
+        self.fatigue_floor = floor.clamp(0.0, 1.0);
+        self
+    }
+
     /// Simulates kicks with given strategies.
     ///
     /// # Arguments
@@ -88,50 +336,281 @@ impl Simulator {
     /// * `gk_strategy` - Probability distribution over GK directions
     /// * `num_kicks` - Number of kicks to simulate
     pub fn simulate(
-        &self,
+        &mut self,
         kicker_strategy: &[f64],
         gk_strategy: &[f64],
         num_kicks: u32,
     ) -> SimulationResult {
+        let kicks = self.with_stream(|sim, rng| sim.run_kicks(rng, kicker_strategy, gk_strategy, 0, num_kicks));
+
+        self.build_result(kicks, kicker_strategy, gk_strategy)
+    }
+
+    /// Simulates `num_pairs` antithetic pairs of kicks.
+    ///
+    /// Each pair shares its kick and dive directions; the goal check uses a
+    /// uniform draw `u` for the first kick and `1 - u` for the second. The
+    /// negatively correlated outcomes reduce the variance of the goal-rate
+    /// estimate compared with `simulate` over the same `2 * num_pairs` kicks.
+    pub fn simulate_antithetic(
+        &mut self,
+        kicker_strategy: &[f64],
+        gk_strategy: &[f64],
+        num_pairs: u32,
+    ) -> SimulationResult {
+        let kicks = self.with_stream(|sim, rng| {
+            let matrix = sim.pk.payoff_matrix().matrix();
+            let mut kicks = Vec::with_capacity(2 * num_pairs as usize);
+
+            for pair in 0..num_pairs {
+                let kick_index = sample_index(rng, kicker_strategy);
+                let gk_index = sample_index(rng, gk_strategy);
+                let base_rate = matrix[kick_index][gk_index];
+                let u = rng.next_f64();
+
+                for (n, draw) in [(2 * pair, u), (2 * pair + 1, 1.0 - u)] {
+                    kicks.push(SimulatedKick {
+                        kick_index,
+                        gk_index,
+                        is_goal: draw < base_rate * sim.fatigue_factor(n),
+                    });
+                }
+            }
+
+            kicks
+        });
+
+        self.build_result(kicks, kicker_strategy, gk_strategy)
+    }
+
+    /// Returns the generator for substream `k` of the seed.
+    ///
+    /// Substream `k` is the seed's stream jumped ahead `k * 2^128` draws, so
+    /// distinct substreams never overlap. Deriving streams from `seed + k`
+    /// instead would give no such guarantee. Costs `k` jumps.
+    pub fn substream(&self, k: usize) -> SimpleRng {
         let mut rng = SimpleRng::new(self.rng_seed);
-        let mut kicks = Vec::with_capacity(num_kicks as usize);
-        let mut goals_scored = 0;
+        for _ in 0..k {
+            rng.jump();
+        }
+        rng
+    }
 
-        let matrix = self.pk.payoff_matrix().matrix();
+    /// Simulates kicks split into `num_chunks` independently seeded chunks.
+    ///
+    /// Chunk `k` draws from `substream(k)`, so the result depends only on
+    /// the seed and the chunk count, not on which thread runs which chunk.
+    /// A generator set with `with_rng` is not used.
+    /// This is the serial counterpart of `simulate_parallel` and produces
+    /// identical output.
+    pub fn simulate_chunked(
+        &self,
+        kicker_strategy: &[f64],
+        gk_strategy: &[f64],
+        num_kicks: u32,
+        num_chunks: usize,
+    ) -> SimulationResult {
+        let kicks = chunk_ranges(num_kicks, num_chunks)
+            .into_iter()
+            .enumerate()
+            .flat_map(|(chunk, range)| self.run_chunk(chunk, range, kicker_strategy, gk_strategy))
+            .collect();
+
+        self.build_result(kicks, kicker_strategy, gk_strategy)
+    }
+
+    /// Simulates kicks across rayon threads, one chunk per task.
+    ///
+    /// Results are reproducible for a given seed and `num_chunks`, and match
+    /// `simulate_chunked` with the same arguments: each chunk draws from its
+    /// own non-overlapping `substream`, whatever the thread count or order.
+    #[cfg(feature = "parallel")]
+    pub fn simulate_parallel(
+        &self,
+        kicker_strategy: &[f64],
+        gk_strategy: &[f64],
+        num_kicks: u32,
+        num_chunks: usize,
+    ) -> SimulationResult
+    where
+        R: Sync,
+    {
+        use rayon::prelude::*;
+
+        let chunks: Vec<Vec<SimulatedKick>> = chunk_ranges(num_kicks, num_chunks)
+            .into_par_iter()
+            .enumerate()
+            .map(|(chunk, range)| self.run_chunk(chunk, range, kicker_strategy, gk_strategy))
+            .collect();
+
+        self.build_result(chunks.concat(), kicker_strategy, gk_strategy)
+    }
+
+    /// Simulates kicks against a goalkeeper who learns the kicker's habits.
+    ///
+    /// The keeper tracks the kicker's directions over the last `learning_window`
+    /// kicks and best-responds to that empirical distribution. Confidence grows
+    /// as the window fills: the keeper best-responds with probability
+    /// `(observed / learning_window) * (1 - exploration_rate)` and otherwise
+    /// dives uniformly at random.
+    ///
+    /// # Arguments
+    /// * `kicker_strategy` - Probability distribution over kick directions
+    /// * `learning_window` - Number of recent kicks the keeper remembers
+    /// * `num_kicks` - Number of kicks to simulate
+    pub fn simulate_adaptive_gk(
+        &mut self,
+        kicker_strategy: &[f64],
+        learning_window: usize,
+        num_kicks: u32,
+    ) -> AdaptiveSimulationResult {
+        self.with_stream(|sim, rng| sim.run_adaptive_gk(rng, kicker_strategy, learning_window, num_kicks))
+    }
+
+    fn run_adaptive_gk(
+        &self,
+        rng: &mut dyn RngSource,
+        kicker_strategy: &[f64],
+        learning_window: usize,
+        num_kicks: u32,
+    ) -> AdaptiveSimulationResult {
+        let payoff = self.pk.payoff_matrix();
+        let matrix = payoff.matrix();
+        let num_kick_options = payoff.num_rows();
+        let num_dives = payoff.num_cols();
+        let learning_window = learning_window.max(1);
+        let uniform = vec![1.0 / num_dives as f64; num_dives];
+
+        let mut window: VecDeque<usize> = VecDeque::with_capacity(learning_window);
+        let mut dive_counts = vec![0u32; num_dives];
+        let mut kicks = Vec::with_capacity(num_kicks as usize);
 
         for _ in 0..num_kicks {
-            // Sample kick direction
-            let kick_dir = sample_direction(&mut rng, kicker_strategy);
-            // Sample GK direction
-            let gk_dir = sample_direction(&mut rng, gk_strategy);
+            let inferred = inferred_distribution(&window, num_kick_options);
+            let confidence = window.len() as f64 / learning_window as f64;
+            let best_response_prob = confidence * (1.0 - self.exploration_rate);
 
-            // Determine if goal based on success rate
-            let success_rate = matrix[kick_dir.index()][gk_dir.index()];
+            let gk_index = if rng.next_f64() < best_response_prob {
+                best_response_dive(matrix, &inferred)
+            } else {
+                sample_index(rng, &uniform)
+            };
+            let kick_index = sample_index(rng, kicker_strategy);
+
+            let success_rate = matrix[kick_index][gk_index];
             let is_goal = rng.next_f64() < success_rate;
 
-            if is_goal {
-                goals_scored += 1;
+            dive_counts[gk_index] += 1;
+            kicks.push(SimulatedKick {
+                kick_index,
+                gk_index,
+                is_goal,
+            });
+
+            if window.len() == learning_window {
+                window.pop_front();
+            }
+            window.push_back(kick_index);
+        }
+
+        let dive_frequencies: Vec<f64> = dive_counts
+            .iter()
+            .map(|&c| if num_kicks == 0 { 0.0 } else { c as f64 / num_kicks as f64 })
+            .collect();
+
+        AdaptiveSimulationResult {
+            result: self.build_result(kicks, kicker_strategy, &dive_frequencies),
+            inferred_kicker_strategy: inferred_distribution(&window, num_kick_options),
+        }
+    }
+
+    /// Runs `f` on the configured generator, or on a fresh seeded stream.
+    fn with_stream<T>(&mut self, f: impl FnOnce(&Self, &mut dyn RngSource) -> T) -> T {
+        match self.rng.take() {
+            Some(mut rng) => {
+                let result = f(self, &mut rng);
+                self.rng = Some(rng);
+                result
             }
+            None => f(self, &mut SimpleRng::new(self.rng_seed)),
+        }
+    }
+
+    /// Runs a single chunk with its own deterministically derived stream.
+    fn run_chunk(
+        &self,
+        chunk: usize,
+        (first_kick, num_kicks): (u32, u32),
+        kicker_strategy: &[f64],
+        gk_strategy: &[f64],
+    ) -> Vec<SimulatedKick> {
+        let mut rng = self.substream(chunk);
+        self.run_kicks(&mut rng, kicker_strategy, gk_strategy, first_kick, num_kicks)
+    }
+
+    /// Draws `num_kicks` kicks from the given generator.
+    ///
+    /// `first_kick` is the session index of the first kick, used for fatigue.
+    fn run_kicks(
+        &self,
+        rng: &mut dyn RngSource,
+        kicker_strategy: &[f64],
+        gk_strategy: &[f64],
+        first_kick: u32,
+        num_kicks: u32,
+    ) -> Vec<SimulatedKick> {
+        let mut kicks = Vec::with_capacity(num_kicks as usize);
+        let matrix = self.pk.payoff_matrix().matrix();
+
+        for n in first_kick..first_kick + num_kicks {
+            // Sample kick action
+            let kick_index = sample_index(rng, kicker_strategy);
+            // Sample GK action
+            let gk_index = sample_index(rng, gk_strategy);
+
+            // Determine if goal based on success rate
+            let success_rate = matrix[kick_index][gk_index] * self.fatigue_factor(n);
+            let is_goal = rng.next_f64() < success_rate;
 
             kicks.push(SimulatedKick {
-                kick_direction: kick_dir,
-                gk_direction: gk_dir,
+                kick_index,
+                gk_index,
                 is_goal,
             });
         }
 
+        kicks
+    }
+
+    /// Multiplier applied to success rates for the `n`-th kick of a session.
+    fn fatigue_factor(&self, n: u32) -> f64 {
+        (1.0 - self.fatigue_decay * n as f64).max(self.fatigue_floor)
+    }
+
+    /// Assembles a result from a kick log.
+    fn build_result(
+        &self,
+        kicks: Vec<SimulatedKick>,
+        kicker_strategy: &[f64],
+        gk_strategy: &[f64],
+    ) -> SimulationResult {
+        let goals_scored = kicks.iter().filter(|k| k.is_goal).count() as u32;
+        let total_kicks = kicks.len() as u32;
+
         SimulationResult {
             kicks,
             goals_scored,
-            total_kicks: num_kicks,
+            total_kicks,
             kicker_strategy: kicker_strategy.to_vec(),
             goalkeeper_strategy: gk_strategy.to_vec(),
+            kick_labels: self.pk.payoff_matrix().row_labels().to_vec(),
+            gk_labels: self.pk.payoff_matrix().col_labels().to_vec(),
         }
     }
 
     /// Compares optimal strategy vs a given strategy.
     pub fn compare_strategies(
-        &self,
+        &mut self,
         alternative_kicker: &[f64],
         alternative_gk: &[f64],
         num_kicks: u32,
@@ -159,6 +638,40 @@ impl Simulator {
         Ok((optimal_result, alternative_result))
     }
 
+    /// Compares the equilibrium with alternative strategies and summarizes the difference.
+    ///
+    /// Runs the same simulations as `compare_strategies`. Significance uses
+    /// `intervals_overlap` at `confidence`, which must lie in (0, 1).
+    pub fn compare_strategies_summary(
+        &mut self,
+        alternative_kicker: &[f64],
+        alternative_gk: &[f64],
+        num_kicks: u32,
+        confidence: f64,
+    ) -> Result<StrategyComparison, AnalysisError> {
+        let (optimal, alternative) = self.compare_strategies(alternative_kicker, alternative_gk, num_kicks)?;
+
+        let kick_percentage = |result: &SimulationResult, direction: Direction| {
+            let (goals, attempts) = result
+                .direction_stats()
+                .into_iter()
+                .filter(|((kick, _), _, _)| *kick == direction)
+                .fold((0, 0), |(g, a), (_, goals, attempts)| (g + goals, a + attempts));
+            if attempts == 0 { 0.0 } else { goals as f64 / attempts as f64 * 100.0 }
+        };
+
+        Ok(StrategyComparison {
+            optimal_goal_percentage: optimal.goal_percentage(),
+            alternative_goal_percentage: alternative.goal_percentage(),
+            difference: optimal.goal_percentage() - alternative.goal_percentage(),
+            significant: !Simulator::intervals_overlap(&optimal, &alternative, confidence)?,
+            direction_breakdown: Direction::all()
+                .iter()
+                .map(|&dir| (dir, kick_percentage(&optimal, dir), kick_percentage(&alternative, dir)))
+                .collect(),
+        })
+    }
+
     /// Returns the underlying PK model.
     pub fn penalty_kick(&self) -> &PenaltyKick {
         &self.pk
@@ -171,41 +684,182 @@ impl Default for Simulator {
     }
 }
 
-/// Simple linear congruential generator for reproducible randomness.
-struct SimpleRng {
-    state: u64,
+/// Name used for action `index` in CSV and JSON exports.
+///
+/// The 3×3 model keeps the lowercase direction names accepted by the stats
+/// CSV loader; other models fall back to the label, then the index.
+fn export_name(labels: &[String], index: usize) -> String {
+    match Direction::from_index(index) {
+        Some(dir) if labels.len() == Direction::all().len() => dir.name().to_lowercase(),
+        _ => labels.get(index).cloned().unwrap_or_else(|| index.to_string()),
+    }
+}
+
+/// Empirical action frequencies over the window (uniform when empty).
+fn inferred_distribution(window: &VecDeque<usize>, num_actions: usize) -> Vec<f64> {
+    if window.is_empty() {
+        return vec![1.0 / num_actions as f64; num_actions];
+    }
+
+    let mut counts = vec![0.0; num_actions];
+    for &index in window {
+        counts[index] += 1.0;
+    }
+    counts.iter().map(|c| c / window.len() as f64).collect()
+}
+
+/// Goalkeeper dive minimizing the expected goal rate against a kicker distribution.
+fn best_response_dive(matrix: &[Vec<f64>], kicker_dist: &[f64]) -> usize {
+    let expected = |j: usize| -> f64 {
+        kicker_dist
+            .iter()
+            .enumerate()
+            .map(|(i, &p)| p * matrix[i][j])
+            .sum()
+    };
+
+    (0..matrix[0].len())
+        .min_by(|&a, &b| expected(a).total_cmp(&expected(b)))
+        .unwrap_or(0)
+}
+
+/// Splits `num_kicks` into `num_chunks` near-equal `(first_kick, size)` ranges.
+fn chunk_ranges(num_kicks: u32, num_chunks: usize) -> Vec<(u32, u32)> {
+    let num_chunks = num_chunks.max(1) as u32;
+    let base = num_kicks / num_chunks;
+    let remainder = num_kicks % num_chunks;
+    let mut first_kick = 0;
+
+    (0..num_chunks)
+        .map(|k| {
+            let size = base + if k < remainder { 1 } else { 0 };
+            let range = (first_kick, size);
+            first_kick += size;
+            range
+        })
+        .collect()
+}
+
+/// Small, fast PRNG used for reproducible simulations.
+///
+/// Implements xoshiro256** (Blackman & Vigna). The 256-bit state is expanded
+/// from the 64-bit seed with SplitMix64, so a given seed produces the same
+/// stream on every platform. `next_f64` takes the top 53 bits of each output,
+/// giving uniformly distributed values in `[0, 1)`.
+pub struct SimpleRng {
+    state: [u64; 4],
 }
 
 impl SimpleRng {
-    fn new(seed: u64) -> Self {
-        Self { state: seed }
+    /// Creates a generator from a 64-bit seed.
+    pub fn new(seed: u64) -> Self {
+        let mut sm = seed;
+        let mut state = [0u64; 4];
+        for word in state.iter_mut() {
+            *word = splitmix64(&mut sm);
+        }
+        Self { state }
     }
 
     fn next_u64(&mut self) -> u64 {
-        // LCG parameters from Numerical Recipes
-        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1);
-        self.state
+        let s = &mut self.state;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
     }
 
-    fn next_f64(&mut self) -> f64 {
+    pub(crate) fn next_f64(&mut self) -> f64 {
         (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
     }
+
+    /// Advances the generator by 2^128 draws.
+    ///
+    /// Uses the xoshiro256** jump polynomial, so `2^128` non-overlapping
+    /// substreams of length `2^128` can be carved out of one seed.
+    pub fn jump(&mut self) {
+        const JUMP: [u64; 4] = [0x180e_c6d3_3cfd_0aba, 0xd5a6_1266_f0c9_392c, 0xa958_2618_e03f_c9aa, 0x39ab_dc45_29b1_661c];
+
+        let mut jumped = [0u64; 4];
+        for word in JUMP {
+            for bit in 0..64 {
+                if word & (1u64 << bit) != 0 {
+                    for (j, s) in jumped.iter_mut().zip(&self.state) {
+                        *j ^= s;
+                    }
+                }
+                self.next_u64();
+            }
+        }
+        self.state = jumped;
+    }
+
+    /// Draws a standard normal sample using the Box-Muller transform.
+    pub(crate) fn next_gaussian(&mut self) -> f64 {
+        // 1 - u lies in (0, 1], keeping the logarithm finite
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
 }
 
-/// Samples a direction based on the given probability distribution.
-fn sample_direction(rng: &mut SimpleRng, probs: &[f64]) -> Direction {
+impl RngSource for SimpleRng {
+    fn next_f64(&mut self) -> f64 {
+        SimpleRng::next_f64(self)
+    }
+}
+
+/// SplitMix64 step, used to expand a seed into xoshiro state.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Samples an action index based on the given probability distribution.
+///
+/// `probs` is normalized by its sum up front, so distributions that do not
+/// add up to exactly 1 keep their intended proportions. Whatever mass
+/// remains after the earlier buckets goes to the last action with positive
+/// probability, so a zero-probability action is never drawn. `probs` must be
+/// non-empty with a positive, finite sum.
+pub(crate) fn sample_index(rng: &mut (impl RngSource + ?Sized), probs: &[f64]) -> usize {
+    let total: f64 = probs.iter().sum();
+    debug_assert!(
+        total.is_finite() && total > 0.0,
+        "cannot sample from {:?}: probabilities must have a positive, finite sum",
+        probs
+    );
+
+    let normalized = probs.iter().map(|&p| p / total);
+    let Some(last) = probs.iter().rposition(|&p| p > 0.0) else {
+        return probs.len().saturating_sub(1);
+    };
     let r = rng.next_f64();
     let mut cumulative = 0.0;
 
-    for (i, &p) in probs.iter().enumerate() {
+    for (i, p) in normalized.enumerate().take(last) {
         cumulative += p;
         if r < cumulative {
-            return Direction::from_index(i).unwrap_or(Direction::Center);
+            return i;
         }
     }
 
-    // Fallback to last direction
-    Direction::from_index(probs.len() - 1).unwrap_or(Direction::Right)
+    last
+}
+
+/// Samples a direction based on the given probability distribution.
+pub(crate) fn sample_direction(rng: &mut (impl RngSource + ?Sized), probs: &[f64]) -> Direction {
+    Direction::from_index(sample_index(rng, probs)).unwrap_or(Direction::Center)
 }
 
 #[cfg(test)]
@@ -214,7 +868,7 @@ mod tests {
 
     #[test]
     fn test_simulation_basic() {
-        let sim = Simulator::new().seed(42);
+        let mut sim = Simulator::new().seed(42);
         let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
 
         let result = sim.simulate(&uniform, &uniform, 1000);
@@ -224,10 +878,50 @@ mod tests {
         assert!(result.goals_scored < 1000);
     }
 
+    /// Replays a fixed sequence of draws, cycling when exhausted.
+    struct ScriptedRng {
+        values: Vec<f64>,
+        position: usize,
+    }
+
+    impl RngSource for ScriptedRng {
+        fn next_f64(&mut self) -> f64 {
+            let value = self.values[self.position % self.values.len()];
+            self.position += 1;
+            value
+        }
+    }
+
+    #[test]
+    fn test_scripted_rng() {
+        // Each kick draws kick direction, dive direction, then the goal check
+        let rng = ScriptedRng {
+            values: vec![0.1, 0.9, 0.5, 0.5, 0.5, 0.5],
+            position: 0,
+        };
+        let mut sim = Simulator::new().with_rng(rng);
+        let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+
+        let result = sim.simulate(&uniform, &uniform, 2);
+
+        // Left vs a right dive scores 95% of the time; Center vs Center only 44%
+        assert_eq!(result.kicks[0].kick_direction(), Some(Direction::Left));
+        assert_eq!(result.kicks[0].gk_direction(), Some(Direction::Right));
+        assert!(result.kicks[0].is_goal);
+        assert_eq!(result.kicks[1].kick_direction(), Some(Direction::Center));
+        assert_eq!(result.kicks[1].gk_direction(), Some(Direction::Center));
+        assert!(!result.kicks[1].is_goal);
+
+        // Chunked runs reseed from the seed instead of the injected generator
+        let chunked = sim.simulate_chunked(&uniform, &uniform, 100, 4);
+        let seeded = Simulator::new().simulate_chunked(&uniform, &uniform, 100, 4);
+        assert_eq!(chunked.goals_scored, seeded.goals_scored);
+    }
+
     #[test]
     fn test_simulation_reproducibility() {
-        let sim1 = Simulator::new().seed(12345);
-        let sim2 = Simulator::new().seed(12345);
+        let mut sim1 = Simulator::new().seed(12345);
+        let mut sim2 = Simulator::new().seed(12345);
 
         let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
 
@@ -238,8 +932,281 @@ mod tests {
     }
 
     #[test]
-    fn test_strategy_comparison() {
+    fn test_uniform_sampling_is_unbiased() {
+        let mut rng = SimpleRng::new(42);
+        let uniform = [1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+        let draws = 100_000;
+        let mut counts = [0u32; 3];
+
+        for _ in 0..draws {
+            counts[sample_direction(&mut rng, &uniform).index()] += 1;
+        }
+
+        for count in counts {
+            let freq = count as f64 / draws as f64;
+            assert!((freq - 1.0 / 3.0).abs() < 0.01, "frequency {} too far from 1/3", freq);
+        }
+    }
+
+    #[test]
+    fn test_sampling_normalizes_probabilities() {
+        let mut rng = SimpleRng::new(7);
+        let probs = [0.2, 0.3, 0.4999999];
+        let total: f64 = probs.iter().sum();
+        let draws = 100_000;
+        let mut counts = [0u32; 3];
+
+        for _ in 0..draws {
+            counts[sample_index(&mut rng, &probs)] += 1;
+        }
+
+        for (count, p) in counts.iter().zip(probs) {
+            let freq = *count as f64 / draws as f64;
+            assert!((freq - p / total).abs() < 0.01, "frequency {} too far from {}", freq, p / total);
+        }
+
+        // A draw beyond an undershooting sum must not land on a zero bucket
+        let mut late = ScriptedRng { values: vec![0.95], position: 0 };
+        assert_eq!(sample_index(&mut late, &[0.45, 0.45, 0.0]), 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_sample_index_rejects_zero_mass() {
+        for probs in [vec![], vec![0.0, 0.0], vec![f64::NAN, 0.5]] {
+            let result = std::panic::catch_unwind(|| sample_index(&mut SimpleRng::new(1), &probs));
+            assert!(result.is_err(), "{:?} was sampled", probs);
+        }
+    }
+
+    #[test]
+    fn test_rng_stream_is_stable() {
+        // Pin the first outputs so the stream stays identical across platforms.
+        let mut rng = SimpleRng::new(0);
+        assert_eq!(rng.next_u64(), 0x99EC_5F36_CB75_F2B4);
+        assert_eq!(rng.next_u64(), 0xBF6E_1F78_4956_452A);
+    }
+
+    #[test]
+    fn test_antithetic_reduces_variance() {
+        let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+        let runs = 400;
+
+        let variance = |rates: Vec<f64>| {
+            let mean = rates.iter().sum::<f64>() / rates.len() as f64;
+            rates.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / rates.len() as f64
+        };
+
+        let plain = variance(
+            (0..runs)
+                .map(|s| Simulator::new().seed(s).simulate(&uniform, &uniform, 100).goal_percentage())
+                .collect(),
+        );
+        let antithetic = variance(
+            (0..runs)
+                .map(|s| {
+                    let result = Simulator::new().seed(s).simulate_antithetic(&uniform, &uniform, 50);
+                    assert_eq!(result.total_kicks, 100);
+                    result.goal_percentage()
+                })
+                .collect(),
+        );
+
+        assert!(antithetic < plain, "antithetic {} vs plain {}", antithetic, plain);
+    }
+
+    #[test]
+    fn test_substreams_uncorrelated() {
         let sim = Simulator::new().seed(42);
+        let (mut first, mut second) = (sim.substream(0), sim.substream(1));
+        let n = 10_000;
+        let a: Vec<f64> = (0..n).map(|_| first.next_f64()).collect();
+        let b: Vec<f64> = (0..n).map(|_| second.next_f64()).collect();
+
+        let mean = |x: &[f64]| x.iter().sum::<f64>() / n as f64;
+        let (mean_a, mean_b) = (mean(&a), mean(&b));
+        let covariance: f64 = a.iter().zip(&b).map(|(x, y)| (x - mean_a) * (y - mean_b)).sum();
+        let spread = |x: &[f64], m: f64| x.iter().map(|v| (v - m).powi(2)).sum::<f64>().sqrt();
+        let correlation = covariance / (spread(&a, mean_a) * spread(&b, mean_b));
+
+        // Standard error of the correlation of independent samples is 0.01
+        assert!(correlation.abs() < 0.04, "correlation {}", correlation);
+        assert_ne!(a[..100], b[..100]);
+
+        // Substreams are deterministic and built from repeated jumps
+        let mut jumped = SimpleRng::new(42);
+        jumped.jump();
+        assert_eq!(jumped.next_f64(), b[0]);
+        assert_eq!(sim.substream(1).next_f64(), b[0]);
+    }
+
+    #[test]
+    fn test_chunked_simulation() {
+        let sim = Simulator::new().seed(7);
+        let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+
+        let result = sim.simulate_chunked(&uniform, &uniform, 1001, 4);
+        let again = sim.simulate_chunked(&uniform, &uniform, 1001, 4);
+
+        assert_eq!(result.total_kicks, 1001);
+        assert_eq!(result.kicks.len(), 1001);
+        assert_eq!(result.goals_scored, again.goals_scored);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_chunked() {
+        let sim = Simulator::new().seed(99);
+        let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+
+        let serial = sim.simulate_chunked(&uniform, &uniform, 10_000, 8);
+        let parallel = sim.simulate_parallel(&uniform, &uniform, 10_000, 8);
+
+        assert_eq!(serial.goals_scored, parallel.goals_scored);
+        assert_eq!(serial.total_kicks, parallel.total_kicks);
+    }
+
+    #[test]
+    fn test_goal_rate_interval() {
+        let mut sim = Simulator::new().seed(42);
+        let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+
+        let small = sim.simulate(&uniform, &uniform, 100);
+        let large = sim.simulate(&uniform, &uniform, 10_000);
+
+        let (small_low, small_high) = small.goal_rate_interval(0.95).unwrap();
+        let (large_low, large_high) = large.goal_rate_interval(0.95).unwrap();
+        assert!(large_high - large_low < small_high - small_low);
+
+        let point = large.goal_percentage() / 100.0;
+        assert!(large_low <= point && point <= large_high);
+        assert!(Simulator::intervals_overlap(&large, &large, 0.95).unwrap());
+        assert!(large.goal_rate_interval(1.0).is_err());
+        assert!(Simulator::intervals_overlap(&large, &large, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_adaptive_gk_exploits_pure_kicker() {
+        let mut sim = Simulator::new().seed(42);
+        let always_left = vec![1.0, 0.0, 0.0];
+
+        let adaptive = sim.simulate_adaptive_gk(&always_left, 100, 2000);
+        let kicks = &adaptive.result.kicks;
+
+        let rate = |slice: &[SimulatedKick]| {
+            slice.iter().filter(|k| k.is_goal).count() as f64 / slice.len() as f64
+        };
+        let early = rate(&kicks[..100]);
+        let late = rate(&kicks[1000..]);
+
+        assert!(late < early, "late rate {} should be below early rate {}", late, early);
+        assert!((adaptive.inferred_kicker_strategy[0] - 1.0).abs() < 1e-9);
+        assert!(adaptive.result.goalkeeper_strategy[0] > 0.5);
+    }
+
+    #[test]
+    fn test_best_response_dive_ignores_nan() {
+        let matrix = vec![vec![0.6, 0.9], vec![0.9, 0.5]];
+
+        // A NaN weight makes every dive's expectation NaN but must not panic
+        assert!(best_response_dive(&matrix, &[f64::NAN, 1.0]) < 2);
+        assert_eq!(best_response_dive(&matrix, &[0.0, 1.0]), 1);
+    }
+
+    #[test]
+    fn test_streaks_and_variance() {
+        let kick = |kick: Direction, gk: Direction, is_goal| SimulatedKick {
+            kick_index: kick.index(),
+            gk_index: gk.index(),
+            is_goal,
+        };
+        let kicks = vec![
+            kick(Direction::Left, Direction::Right, true),
+            kick(Direction::Left, Direction::Right, true),
+            kick(Direction::Center, Direction::Center, false),
+            kick(Direction::Left, Direction::Right, true),
+            kick(Direction::Center, Direction::Center, true),
+            kick(Direction::Left, Direction::Right, true),
+            kick(Direction::Right, Direction::Right, false),
+            kick(Direction::Right, Direction::Right, false),
+            kick(Direction::Right, Direction::Right, false),
+        ];
+        let result = SimulationResult {
+            goals_scored: 5,
+            total_kicks: kicks.len() as u32,
+            kicks,
+            kicker_strategy: vec![],
+            goalkeeper_strategy: vec![],
+            kick_labels: vec![],
+            gk_labels: vec![],
+        };
+
+        assert_eq!(result.longest_goal_streak(), 3);
+        assert_eq!(result.longest_miss_streak(), 3);
+
+        let variance = result.direction_variance();
+        let cell = |k, g| variance.iter().find(|(d, _)| *d == (k, g)).unwrap().1;
+        assert_eq!(cell(Direction::Left, Direction::Right), 0.0);
+        assert_eq!(cell(Direction::Right, Direction::Right), 0.0);
+        assert!((cell(Direction::Center, Direction::Center) - 0.25).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fatigue_erodes_goal_rate() {
+        let mut sim = Simulator::new().seed(42).with_fatigue(0.0005, 0.5);
+        let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+
+        let result = sim.simulate(&uniform, &uniform, 2000);
+        let rate = |slice: &[SimulatedKick]| {
+            slice.iter().filter(|k| k.is_goal).count() as f64 / slice.len() as f64
+        };
+
+        let first_quartile = rate(&result.kicks[..500]);
+        let last_quartile = rate(&result.kicks[1500..]);
+        assert!(last_quartile < first_quartile - 0.1);
+    }
+
+    #[test]
+    fn test_csv_and_json_export() {
+        let mut sim = Simulator::new().seed(42);
+        let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+        let result = sim.simulate(&uniform, &uniform, 250);
+
+        let csv = result.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), result.total_kicks as usize + 1);
+        assert_eq!(lines[0], "index,kick_direction,gk_direction,is_goal");
+        assert!(lines[1].starts_with("0,"));
+
+        let json: serde_json::Value = serde_json::from_str(&result.to_json()).unwrap();
+        assert_eq!(json["goals_scored"], result.goals_scored);
+        assert_eq!(json["total_kicks"], 250);
+        let stats = json["direction_stats"].as_array().unwrap();
+        assert!(stats.iter().all(|s| s["kick_direction"].as_str().unwrap().chars().all(char::is_lowercase)));
+    }
+
+    #[test]
+    fn test_four_by_four_simulation() {
+        let matrix: Vec<Vec<f64>> = (0..4)
+            .map(|i| (0..4).map(|j| if i == j { 0.4 } else { 0.9 }).collect())
+            .collect();
+        let mut sim = Simulator::with_matrix(matrix).unwrap().seed(42);
+        let uniform = vec![0.25; 4];
+
+        let result = sim.simulate(&uniform, &uniform, 4000);
+        let stats = result.index_stats();
+
+        assert_eq!(stats.len(), 16);
+        assert_eq!(stats.iter().map(|(_, _, a)| a).sum::<u32>(), 4000);
+        assert_eq!(stats.iter().map(|(_, g, _)| g).sum::<u32>(), result.goals_scored);
+        assert_eq!(stats[15].0, (3, 3));
+        assert_eq!(result.kick_label(3), Some("Kick 4"));
+        assert!(result.to_csv().lines().skip(1).all(|line| line.contains("Kick ")));
+    }
+
+    #[test]
+    fn test_strategy_comparison() {
+        let mut sim = Simulator::new().seed(42);
         let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
 
         let (optimal, alternative) = sim.compare_strategies(&uniform, &uniform, 1000).unwrap();
@@ -248,4 +1215,23 @@ mod tests {
         assert_eq!(optimal.total_kicks, 1000);
         assert_eq!(alternative.total_kicks, 1000);
     }
+
+    #[test]
+    fn test_strategy_comparison_summary() {
+        let mut sim = Simulator::new().seed(42);
+        let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+
+        // Equilibrium scores 78.3% against 77.7% for uniform play, so the
+        // gap only clears the 95% intervals with a large sample
+        let summary = sim.compare_strategies_summary(&uniform, &uniform, 500_000, 0.95).unwrap();
+
+        assert!(summary.difference > 0.0);
+        assert!(summary.significant);
+        assert_eq!(
+            summary.difference,
+            summary.optimal_goal_percentage - summary.alternative_goal_percentage
+        );
+        assert_eq!(summary.direction_breakdown.len(), 3);
+        assert_eq!(summary.direction_breakdown[1].0, Direction::Center);
+    }
 }
@@ -1,3 +1,5 @@
+use std::ops::Deref;
+use std::str::FromStr;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,6 +8,100 @@ pub enum PayoffError {
     DimensionMismatch,
     #[error("Invalid probability: {0}")]
     InvalidProbability(f64),
+    #[error("Empty payoff matrix")]
+    EmptyMatrix,
+    #[error("Invalid number: {0}")]
+    InvalidNumber(String),
+    #[error("Payoff transform with gain {gain} and loss {loss} can't be inverted")]
+    DegenerateTransform { gain: f64, loss: f64 },
+}
+
+/// A probability known to lie in [0, 1].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Probability(f64);
+
+impl Probability {
+    /// Checks that `value` is in [0, 1], rejecting NaN.
+    pub fn new(value: f64) -> Result<Self, PayoffError> {
+        if (0.0..=1.0).contains(&value) {
+            Ok(Self(value))
+        } else {
+            Err(PayoffError::InvalidProbability(value))
+        }
+    }
+
+    /// Returns the probability as a bare `f64`.
+    pub fn value(self) -> f64 {
+        self.0
+    }
+}
+
+impl Deref for Probability {
+    type Target = f64;
+
+    fn deref(&self) -> &f64 {
+        &self.0
+    }
+}
+
+impl TryFrom<f64> for Probability {
+    type Error = PayoffError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+/// Conversion from success probability to the kicker's payoff.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum PayoffTransform {
+    /// Goal = +1, save = -1
+    #[default]
+    Symmetric,
+    /// Goal = `gain`, save = `loss`; build with `affine` to validate
+    Affine { gain: f64, loss: f64 },
+    /// Arbitrary per-cell function, e.g. to model risk aversion
+    Custom(fn(f64) -> f64),
+}
+
+impl PayoffTransform {
+    /// Creates an affine transform, rejecting equal or non-finite payoffs.
+    ///
+    /// A success probability `p` maps to `loss + (gain - loss) * p`, which
+    /// can only be inverted when the two payoffs differ.
+    pub fn affine(gain: f64, loss: f64) -> Result<Self, PayoffError> {
+        if !gain.is_finite() || !loss.is_finite() || gain == loss {
+            return Err(PayoffError::DegenerateTransform { gain, loss });
+        }
+        Ok(PayoffTransform::Affine { gain, loss })
+    }
+
+    /// Returns the `(gain, loss)` coefficients, or `None` for `Custom`.
+    pub fn coefficients(&self) -> Option<(f64, f64)> {
+        match *self {
+            PayoffTransform::Symmetric => Some((1.0, -1.0)),
+            PayoffTransform::Affine { gain, loss } => Some((gain, loss)),
+            PayoffTransform::Custom(_) => None,
+        }
+    }
+
+    /// Applies the transform to a single success probability.
+    pub fn apply(&self, probability: f64) -> f64 {
+        match *self {
+            PayoffTransform::Symmetric => 2.0 * probability - 1.0,
+            PayoffTransform::Affine { gain, loss } => loss + (gain - loss) * probability,
+            PayoffTransform::Custom(f) => f(probability),
+        }
+    }
+
+    /// Recovers the success probability from an expected payoff.
+    ///
+    /// Returns `None` for `Custom` transforms and for affine ones whose
+    /// gain equals their loss, which can't be inverted.
+    pub fn probability(&self, payoff: f64) -> Option<f64> {
+        let (gain, loss) = self.coefficients()?;
+        (gain != loss).then(|| (payoff - loss) / (gain - loss))
+    }
 }
 
 /// Represents a payoff matrix for a two-player game.
@@ -23,17 +119,16 @@ pub struct PayoffMatrix {
 
 impl PayoffMatrix {
     /// Creates a new payoff matrix with the given data and labels.
+    ///
+    /// Matrices with no rows or no columns are rejected with `EmptyMatrix`,
+    /// matching `GameSolver::new`.
     pub fn new(
         matrix: Vec<Vec<f64>>,
         row_labels: Vec<String>,
         col_labels: Vec<String>,
     ) -> Result<Self, PayoffError> {
-        if matrix.is_empty() {
-            return Ok(Self {
-                matrix,
-                row_labels,
-                col_labels,
-            });
+        if matrix.is_empty() || matrix[0].is_empty() {
+            return Err(PayoffError::EmptyMatrix);
         }
 
         let num_cols = matrix[0].len();
@@ -54,12 +149,16 @@ impl PayoffMatrix {
         })
     }
 
-    /// Creates a payoff matrix from raw success probabilities.
+    /// Creates a matrix of success probabilities with the given labels.
     ///
-    /// # Arguments
-    /// * `success_rates` - 2D array of goal success probabilities (0.0 to 1.0)
-    pub fn from_success_rates(success_rates: Vec<Vec<f64>>) -> Result<Self, PayoffError> {
-        for row in &success_rates {
+    /// Like `new`, but also rejects values outside [0, 1]. Use `new` for
+    /// raw payoff matrices that legitimately leave that range.
+    pub fn new_probabilities(
+        matrix: Vec<Vec<f64>>,
+        row_labels: Vec<String>,
+        col_labels: Vec<String>,
+    ) -> Result<Self, PayoffError> {
+        for row in &matrix {
             for &prob in row {
                 if !(0.0..=1.0).contains(&prob) {
                     return Err(PayoffError::InvalidProbability(prob));
@@ -67,13 +166,38 @@ impl PayoffMatrix {
             }
         }
 
+        Self::new(matrix, row_labels, col_labels)
+    }
+
+    /// Creates a matrix of already validated success probabilities.
+    ///
+    /// Only the shape can fail to check, with `EmptyMatrix` or
+    /// `DimensionMismatch` as in `new`.
+    pub fn from_probabilities(
+        matrix: Vec<Vec<Probability>>,
+        row_labels: Vec<String>,
+        col_labels: Vec<String>,
+    ) -> Result<Self, PayoffError> {
+        let matrix = matrix
+            .into_iter()
+            .map(|row| row.into_iter().map(Probability::value).collect())
+            .collect();
+
+        Self::new(matrix, row_labels, col_labels)
+    }
+
+    /// Creates a payoff matrix from raw success probabilities.
+    ///
+    /// # Arguments
+    /// * `success_rates` - 2D array of goal success probabilities (0.0 to 1.0)
+    pub fn from_success_rates(success_rates: Vec<Vec<f64>>) -> Result<Self, PayoffError> {
         let num_rows = success_rates.len();
         let num_cols = if num_rows > 0 { success_rates[0].len() } else { 0 };
 
         let row_labels = (0..num_rows).map(|i| format!("Row {}", i)).collect();
         let col_labels = (0..num_cols).map(|j| format!("Col {}", j)).collect();
 
-        Self::new(success_rates, row_labels, col_labels)
+        Self::new_probabilities(success_rates, row_labels, col_labels)
     }
 
     /// Returns the raw payoff matrix.
@@ -86,6 +210,42 @@ impl PayoffMatrix {
         self.matrix.get(row).and_then(|r| r.get(col).copied())
     }
 
+    /// Returns row `i`, or `None` if out of range.
+    pub fn row(&self, i: usize) -> Option<&[f64]> {
+        self.matrix.get(i).map(|r| r.as_slice())
+    }
+
+    /// Returns column `j`, or `None` if out of range.
+    pub fn column(&self, j: usize) -> Option<Vec<f64>> {
+        if j >= self.num_cols() {
+            return None;
+        }
+        Some(self.matrix.iter().map(|r| r[j]).collect())
+    }
+
+    /// Iterates over every pure outcome as `(row, col, payoff)`, row by row.
+    pub fn outcomes(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        self.matrix
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, &value)| (i, j, value)))
+    }
+
+    /// Returns the transposed matrix with row and column labels swapped.
+    ///
+    /// Useful for analyzing the game from the goalkeeper's side.
+    pub fn transpose(&self) -> PayoffMatrix {
+        let matrix = (0..self.num_cols())
+            .map(|j| self.matrix.iter().map(|r| r[j]).collect())
+            .collect();
+
+        PayoffMatrix {
+            matrix,
+            row_labels: self.col_labels.clone(),
+            col_labels: self.row_labels.clone(),
+        }
+    }
+
     /// Returns the number of rows (Row player's strategies).
     pub fn num_rows(&self) -> usize {
         self.matrix.len()
@@ -114,13 +274,14 @@ impl PayoffMatrix {
     ///
     /// For PK: goal = +1, save = -1 (from kicker's perspective)
     pub fn to_expected_payoff(&self) -> Vec<Vec<f64>> {
+        self.to_expected_payoff_with(&PayoffTransform::Symmetric)
+    }
+
+    /// Converts success probabilities to payoffs using `transform`.
+    pub fn to_expected_payoff_with(&self, transform: &PayoffTransform) -> Vec<Vec<f64>> {
         self.matrix
             .iter()
-            .map(|row| {
-                row.iter()
-                    .map(|&prob| 2.0 * prob - 1.0) // Maps [0,1] to [-1,1]
-                    .collect()
-            })
+            .map(|row| row.iter().map(|&prob| transform.apply(prob)).collect())
             .collect()
     }
 
@@ -146,15 +307,110 @@ impl PayoffMatrix {
 
         output
     }
+
+    /// Pretty-prints each success rate next to its payoff under `transform`.
+    ///
+    /// Cells read like `0.58 (+0.16)`, making the translation into the
+    /// zero-sum game the solver sees explicit.
+    pub fn display_with_payoffs(&self, transform: &PayoffTransform) -> String {
+        let mut output = String::new();
+
+        // Header row
+        output.push_str(&format!("{:>12}", ""));
+        for label in &self.col_labels {
+            output.push_str(&format!("{:>16}", label));
+        }
+        output.push('\n');
+
+        // Data rows
+        for (i, row) in self.matrix.iter().enumerate() {
+            output.push_str(&format!("{:>12}", &self.row_labels[i]));
+            for &val in row {
+                let cell = format!("{:.2} ({:+.2})", val, transform.apply(val));
+                output.push_str(&format!("{:>16}", cell));
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Formats the matrix as a LaTeX `tabular` with labeled rows and columns.
+    ///
+    /// Labels are escaped for LaTeX; values are printed to three decimals.
+    pub fn to_latex(&self) -> String {
+        let mut output = format!("\\begin{{tabular}}{{l|{}}}\n", "c".repeat(self.num_cols()));
+
+        let header: Vec<String> = self.col_labels.iter().map(|label| latex_escape(label)).collect();
+        output.push_str(&format!(" & {} \\\\\n\\hline\n", header.join(" & ")));
+
+        for (label, row) in self.row_labels.iter().zip(&self.matrix) {
+            let cells: Vec<String> = row.iter().map(|val| format!("{:.3}", val)).collect();
+            output.push_str(&format!("{} & {} \\\\\n", latex_escape(label), cells.join(" & ")));
+        }
+
+        output.push_str("\\end{tabular}\n");
+        output
+    }
 }
 
-impl Default for PayoffMatrix {
-    fn default() -> Self {
-        Self {
-            matrix: Vec::new(),
-            row_labels: Vec::new(),
-            col_labels: Vec::new(),
+/// Escapes characters that are special in LaTeX text.
+fn latex_escape(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => format!("\\{}", c),
+            '~' => "\\textasciitilde{}".to_string(),
+            '^' => "\\textasciicircum{}".to_string(),
+            '\\' => "\\textbackslash{}".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Parses a matrix of success probabilities from whitespace-separated text.
+///
+/// Each non-blank line is one kicker row. An optional first line made up
+/// only of non-numeric tokens gives the column labels, and a row may start
+/// with a non-numeric token naming it. Missing labels default to the
+/// `from_success_rates` names. Ragged rows are rejected with
+/// `DimensionMismatch` and values outside [0, 1] with `InvalidProbability`.
+impl FromStr for PayoffMatrix {
+    type Err = PayoffError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let is_number = |token: &str| token.parse::<f64>().is_ok();
+        let mut lines = s
+            .lines()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>())
+            .filter(|tokens| !tokens.is_empty())
+            .peekable();
+
+        let header = lines
+            .next_if(|tokens| !tokens.iter().any(|t| is_number(t)))
+            .map(|tokens| tokens.iter().map(|t| t.to_string()).collect::<Vec<_>>());
+
+        let mut matrix = Vec::new();
+        let mut row_labels = Vec::new();
+
+        for (i, tokens) in lines.enumerate() {
+            let (label, values) = match tokens.split_first() {
+                Some((first, rest)) if !is_number(first) => (first.to_string(), rest),
+                _ => (format!("Row {}", i), &tokens[..]),
+            };
+
+            let row = values
+                .iter()
+                .map(|t| t.parse::<f64>().map_err(|_| PayoffError::InvalidNumber(t.to_string())))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            row_labels.push(label);
+            matrix.push(row);
         }
+
+        let num_cols = matrix.first().map_or(0, |row| row.len());
+        let col_labels = header.unwrap_or_else(|| (0..num_cols).map(|j| format!("Col {}", j)).collect());
+
+        Self::new_probabilities(matrix, row_labels, col_labels)
     }
 }
 
@@ -162,6 +418,39 @@ impl Default for PayoffMatrix {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_probability_newtype() {
+        assert!(matches!(Probability::new(1.3), Err(PayoffError::InvalidProbability(p)) if p == 1.3));
+        assert!(Probability::new(-0.1).is_err());
+        assert!(Probability::new(f64::NAN).is_err());
+
+        let p = Probability::new(0.58).unwrap();
+        assert_eq!(*p, 0.58);
+        assert_eq!(p.max(0.6), 0.6);
+        assert_eq!(Probability::try_from(1.0).unwrap().value(), 1.0);
+    }
+
+    #[test]
+    fn test_to_latex() {
+        let matrix = PayoffMatrix::new(
+            vec![vec![0.58, 0.93, 0.95], vec![0.83, 0.44, 0.83]],
+            vec!["Kick Left".into(), "Kick_Center".into()],
+            vec!["GK Left".into(), "GK Center".into(), "GK 100%".into()],
+        )
+        .unwrap();
+
+        let latex = matrix.to_latex();
+
+        assert!(latex.starts_with("\\begin{tabular}{l|ccc}"));
+        assert!(latex.contains("Kick\\_Center & 0.830 & 0.440 & 0.830 \\\\"));
+        assert!(latex.contains("GK 100\\%"));
+        let rows: Vec<&str> = latex.lines().filter(|line| line.ends_with("\\\\")).collect();
+        assert_eq!(rows.len(), 3);
+        for row in rows {
+            assert_eq!(row.matches(" & ").count(), 3, "{}", row);
+        }
+    }
+
     #[test]
     fn test_payoff_matrix_creation() {
         let matrix = vec![
@@ -192,4 +481,135 @@ mod tests {
         assert!((expected[1][0] - (-1.0)).abs() < 0.001); // 0.0 -> -1.0
         assert!((expected[1][1] - 0.5).abs() < 0.001); // 0.75 -> 0.5
     }
+
+    #[test]
+    fn test_empty_matrix_rejected() {
+        assert!(matches!(
+            PayoffMatrix::new(vec![], vec![], vec![]),
+            Err(PayoffError::EmptyMatrix)
+        ));
+        assert!(matches!(
+            PayoffMatrix::new(vec![vec![], vec![]], vec!["A".into(), "B".into()], vec![]),
+            Err(PayoffError::EmptyMatrix)
+        ));
+        assert!(matches!(
+            PayoffMatrix::from_success_rates(vec![]),
+            Err(PayoffError::EmptyMatrix)
+        ));
+    }
+
+    #[test]
+    fn test_display_with_payoffs() {
+        let payoff = PayoffMatrix::from_success_rates(vec![vec![0.58, 0.93], vec![0.83, 0.44]]).unwrap();
+
+        let symmetric = payoff.display_with_payoffs(&PayoffTransform::Symmetric);
+        assert!(symmetric.contains("0.58 (+0.16)"));
+        assert!(symmetric.contains("0.44 (-0.12)"));
+
+        let goal_only = payoff.display_with_payoffs(&PayoffTransform::Affine { gain: 1.0, loss: 0.0 });
+        assert!(goal_only.contains("0.58 (+0.58)"));
+    }
+
+    #[test]
+    fn test_parse_from_str() {
+        let payoff: PayoffMatrix = "0.58 0.93 0.95\n0.83 0.44 0.83\n\n0.93 0.90 0.60\n".parse().unwrap();
+
+        assert_eq!(payoff.num_rows(), 3);
+        assert_eq!(payoff.get(2, 2), Some(0.60));
+        assert_eq!(payoff.row_labels()[1], "Row 1");
+        assert_eq!(payoff.col_labels()[0], "Col 0");
+
+        assert!(matches!(
+            "0.5 0.6\n0.7\n".parse::<PayoffMatrix>(),
+            Err(PayoffError::DimensionMismatch)
+        ));
+        assert!(matches!(
+            "0.5 high\n".parse::<PayoffMatrix>(),
+            Err(PayoffError::InvalidNumber(t)) if t == "high"
+        ));
+    }
+
+    #[test]
+    fn test_parse_labels_from_str() {
+        let text = "Left Right\nLow 0.70 0.90\nHigh 0.95 0.60\n";
+        let payoff: PayoffMatrix = text.parse().unwrap();
+
+        assert_eq!(payoff.col_labels(), ["Left", "Right"]);
+        assert_eq!(payoff.row_labels(), ["Low", "High"]);
+        assert_eq!(payoff.get(1, 0), Some(0.95));
+    }
+
+    #[test]
+    fn test_outcomes() {
+        let matrix = PayoffMatrix::from_success_rates(vec![vec![0.1, 0.2], vec![0.3, 0.4]]).unwrap();
+        let outcomes: Vec<_> = matrix.outcomes().collect();
+
+        assert_eq!(outcomes, vec![(0, 0, 0.1), (0, 1, 0.2), (1, 0, 0.3), (1, 1, 0.4)]);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let payoff = PayoffMatrix::new(
+            vec![vec![0.1, 0.2, 0.3], vec![0.4, 0.5, 0.6]],
+            vec!["A".into(), "B".into()],
+            vec!["X".into(), "Y".into(), "Z".into()],
+        )
+        .unwrap();
+
+        let transposed = payoff.transpose();
+
+        assert_eq!(transposed.num_rows(), 3);
+        assert_eq!(transposed.row_labels(), ["X", "Y", "Z"]);
+        assert_eq!(transposed.col_labels(), ["A", "B"]);
+        assert_eq!(transposed.row(2), Some(&[0.3, 0.6][..]));
+        assert_eq!(payoff.column(1), Some(vec![0.2, 0.5]));
+        assert_eq!(payoff.column(3), None);
+
+        let round_trip = transposed.transpose();
+        assert_eq!(round_trip.matrix(), payoff.matrix());
+        assert_eq!(round_trip.row_labels(), payoff.row_labels());
+        assert_eq!(round_trip.col_labels(), payoff.col_labels());
+    }
+
+    #[test]
+    fn test_affine_transform_matches_default() {
+        let payoff = PayoffMatrix::from_success_rates(vec![vec![0.58, 0.93], vec![0.83, 0.44]]).unwrap();
+
+        let affine = payoff.to_expected_payoff_with(&PayoffTransform::Affine { gain: 1.0, loss: -1.0 });
+
+        assert_eq!(affine, payoff.to_expected_payoff());
+    }
+
+    #[test]
+    fn test_affine_transform_inverts() {
+        let transform = PayoffTransform::affine(3.0, 0.0).unwrap();
+        assert_eq!(transform.apply(0.25), 0.75);
+        assert_eq!(transform.probability(0.75), Some(0.25));
+        assert_eq!(PayoffTransform::Symmetric.probability(0.0), Some(0.5));
+        assert_eq!(PayoffTransform::Custom(|p| p).probability(0.5), None);
+
+        assert!(matches!(
+            PayoffTransform::affine(1.0, 1.0),
+            Err(PayoffError::DegenerateTransform { .. })
+        ));
+        assert!(PayoffTransform::affine(f64::NAN, 0.0).is_err());
+        assert_eq!(PayoffTransform::Affine { gain: 1.0, loss: 1.0 }.probability(1.0), None);
+    }
+
+    #[test]
+    fn test_skewed_transform_shifts_equilibrium() {
+        use crate::solver::game::GameSolver;
+
+        let payoff = PayoffMatrix::from_success_rates(vec![vec![0.58, 0.93], vec![0.83, 0.44]]).unwrap();
+        // Concave utility: a sure goal is worth more than the expectation suggests
+        let risk_averse = PayoffTransform::Custom(|p| p.sqrt());
+
+        let standard = GameSolver::new(payoff.to_expected_payoff()).unwrap().solve().unwrap();
+        let skewed = GameSolver::new(payoff.to_expected_payoff_with(&risk_averse))
+            .unwrap()
+            .solve()
+            .unwrap();
+
+        assert!((standard.row_strategy[0] - skewed.row_strategy[0]).abs() > 0.01);
+    }
 }
@@ -1,5 +1,21 @@
-use super::payoff::PayoffMatrix;
-use crate::solver::game::{GameSolver, GameSolution, GameError};
+use super::payoff::{PayoffError, PayoffMatrix, PayoffTransform, Probability};
+use super::stats::{direction_to_index, records_to_matrix, PkRecord, StatsError};
+use crate::analysis::divergence::strategy_kl;
+use crate::solver::game::{strategies_match, GameSolver, GameSolution, GameError, VERIFY_TOLERANCE};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Any failure on the way from raw records to an analysis.
+#[derive(Error, Debug)]
+pub enum AnalysisError {
+    #[error("Failed to load stats: {0}")]
+    Stats(#[from] StatsError),
+    #[error("Invalid success rates: {0}")]
+    Payoff(#[from] PayoffError),
+    #[error("Failed to solve game: {0}")]
+    Game(#[from] GameError),
+}
 
 /// Represents the direction of a kick or dive.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -44,6 +60,42 @@ impl Direction {
     }
 }
 
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+/// Parses the same spellings as the stats CSV loader, e.g. "left", "C", "centre".
+impl FromStr for Direction {
+    type Err = StatsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let index = direction_to_index(s)?;
+        Ok(Direction::from_index(index).expect("direction_to_index returns 0..3"))
+    }
+}
+
+/// The kicker's stronger foot.
+///
+/// A right-footed kicker's natural side is the keeper's right (the
+/// kicker's left); a left-footed kicker's is the mirror image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Foot {
+    Left,
+    Right,
+}
+
+impl Foot {
+    /// Returns the other foot.
+    pub fn opposite(&self) -> Foot {
+        match self {
+            Foot::Left => Foot::Right,
+            Foot::Right => Foot::Left,
+        }
+    }
+}
+
 /// Result of analyzing a penalty kick scenario.
 #[derive(Debug, Clone)]
 pub struct PenaltyAnalysis {
@@ -57,12 +109,147 @@ pub struct PenaltyAnalysis {
     pub payoff_matrix: PayoffMatrix,
 }
 
+/// Result of analyzing a penalty kick game of any shape.
+///
+/// Strategies are keyed by the payoff matrix labels rather than by
+/// `Direction`, so games with more or fewer than three options per side
+/// keep every action.
+#[derive(Debug, Clone)]
+pub struct LabeledAnalysis {
+    /// Optimal strategy for the kicker
+    pub kicker_strategy: Vec<(String, f64)>,
+    /// Optimal strategy for the goalkeeper
+    pub goalkeeper_strategy: Vec<(String, f64)>,
+    /// Expected goal probability at equilibrium
+    pub goal_probability: f64,
+}
+
+/// Result of analyzing a penalty kick from the goalkeeper's side.
+///
+/// The keeper is the maximizer and the objective is the save rate, i.e.
+/// `1 - success_rate` for each kick and dive.
+#[derive(Debug, Clone)]
+pub struct SaveAnalysis {
+    /// Optimal strategy for the goalkeeper (maximizer)
+    pub goalkeeper_strategy: Vec<(Direction, f64)>,
+    /// Optimal strategy for the kicker (minimizer)
+    pub kicker_strategy: Vec<(Direction, f64)>,
+    /// Expected save probability at equilibrium
+    pub save_probability: f64,
+    /// Save rates indexed `[kick][dive]`
+    pub payoff_matrix: PayoffMatrix,
+}
+
+/// Remaining probability mass too small to renormalize.
+const PROBABILITY_EPSILON: f64 = 1e-9;
+
+/// Probability below which a direction is treated as unplayed.
+pub(crate) const SUPPORT_THRESHOLD: f64 = 0.001;
+
 impl PenaltyAnalysis {
+    /// Returns the kick directions played with probability above `threshold`.
+    pub fn kicker_support(&self, threshold: f64) -> Vec<Direction> {
+        support(&self.kicker_strategy, threshold)
+    }
+
+    /// Returns the dive directions played with probability above `threshold`.
+    pub fn goalkeeper_support(&self, threshold: f64) -> Vec<Direction> {
+        support(&self.goalkeeper_strategy, threshold)
+    }
+
+    /// Returns true if either player's optimal strategy is pure.
+    pub fn is_pure(&self) -> bool {
+        self.kicker_support(SUPPORT_THRESHOLD).len() == 1
+            || self.goalkeeper_support(SUPPORT_THRESHOLD).len() == 1
+    }
+
+    /// Returns true if strategies and goal probability agree with `other` within `tol`.
+    ///
+    /// Strategies are matched by `Direction`, so their order doesn't matter.
+    pub fn approx_eq(&self, other: &PenaltyAnalysis, tol: f64) -> bool {
+        let matches = |a: &[(Direction, f64)], b: &[(Direction, f64)]| {
+            a.len() == b.len()
+                && a.iter().all(|(dir, p)| {
+                    b.iter().any(|(other_dir, q)| other_dir == dir && (p - q).abs() <= tol)
+                })
+        };
+
+        (self.goal_probability - other.goal_probability).abs() <= tol
+            && matches(&self.kicker_strategy, &other.kicker_strategy)
+            && matches(&self.goalkeeper_strategy, &other.goalkeeper_strategy)
+    }
+
+    /// Returns true if kicker and goalkeeper mix identically, within `tol`.
+    pub fn is_symmetric_equilibrium(&self, tol: f64) -> bool {
+        let kicker: Vec<f64> = self.kicker_strategy.iter().map(|(_, p)| *p).collect();
+        let goalkeeper: Vec<f64> = self.goalkeeper_strategy.iter().map(|(_, p)| *p).collect();
+        strategies_match(&kicker, &goalkeeper, tol)
+    }
+
+    /// Shannon entropy of the kicker's strategy in bits.
+    ///
+    /// 0 for a pure strategy, log2(3) ≈ 1.585 for a uniform three-way mix.
+    pub fn kicker_entropy(&self) -> f64 {
+        entropy(&self.kicker_strategy)
+    }
+
+    /// Shannon entropy of the goalkeeper's strategy in bits.
+    pub fn goalkeeper_entropy(&self) -> f64 {
+        entropy(&self.goalkeeper_strategy)
+    }
+
+    /// Goal probability the equilibrium kicker gets against each pure dive.
+    ///
+    /// By the indifference principle, every dive in the keeper's support
+    /// yields the equilibrium goal probability and dives outside it yield
+    /// at least that much.
+    pub fn gk_indifference_payoffs(&self) -> Vec<(Direction, f64)> {
+        let matrix = self.payoff_matrix.matrix();
+        let payoffs: Vec<f64> = (0..self.payoff_matrix.num_cols())
+            .map(|j| self.kicker_strategy.iter().map(|(dir, p)| p * matrix[dir.index()][j]).sum())
+            .collect();
+        by_direction(&payoffs)
+    }
+
+    /// Goal probability the kicker gains if the keeper drops each dive.
+    ///
+    /// For each direction the keeper's remaining probabilities are
+    /// renormalized, the kicker best-responds to that mix, and the result is
+    /// the rise over the equilibrium goal probability. Dives outside the
+    /// support contribute 0; a pure keeper's only dive gets `None`, since
+    /// nothing is left to renormalize.
+    pub fn gk_action_contribution(&self) -> Vec<(Direction, Option<f64>)> {
+        let matrix = self.payoff_matrix.matrix();
+
+        self.goalkeeper_strategy
+            .iter()
+            .map(|&(dropped, q)| {
+                let remaining = 1.0 - q;
+                if remaining <= PROBABILITY_EPSILON {
+                    return (dropped, None);
+                }
+
+                let best_response = matrix
+                    .iter()
+                    .map(|row| {
+                        self.goalkeeper_strategy
+                            .iter()
+                            .filter(|(dir, _)| *dir != dropped)
+                            .map(|(dir, p)| p / remaining * row[dir.index()])
+                            .sum::<f64>()
+                    })
+                    .fold(f64::NEG_INFINITY, f64::max);
+
+                (dropped, Some((best_response - self.goal_probability).max(0.0)))
+            })
+            .collect()
+    }
+
     /// Formats the kicker's strategy as a readable string.
     pub fn kicker_strategy_string(&self) -> String {
         self.kicker_strategy
             .iter()
-            .filter(|(_, prob)| *prob > 0.001)
+            .filter(|(_, prob)| *prob > SUPPORT_THRESHOLD)
             .map(|(dir, prob)| format!("{}: {:.1}%", dir.name(), prob * 100.0))
             .collect::<Vec<_>>()
             .join(", ")
@@ -72,13 +259,27 @@ impl PenaltyAnalysis {
     pub fn goalkeeper_strategy_string(&self) -> String {
         self.goalkeeper_strategy
             .iter()
-            .filter(|(_, prob)| *prob > 0.001)
+            .filter(|(_, prob)| *prob > SUPPORT_THRESHOLD)
             .map(|(dir, prob)| format!("{}: {:.1}%", dir.name(), prob * 100.0))
             .collect::<Vec<_>>()
             .join(", ")
     }
 }
 
+/// Boxed summary of both strategies and the equilibrium goal rate.
+impl fmt::Display for PenaltyAnalysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "╔════════════════════════════════════════════════════════════╗")?;
+        writeln!(f, "║                    NASH EQUILIBRIUM                        ║")?;
+        writeln!(f, "╠════════════════════════════════════════════════════════════╣")?;
+        writeln!(f, "║  Kicker:     {:<49}║", self.kicker_strategy_string())?;
+        writeln!(f, "║  Goalkeeper: {:<49}║", self.goalkeeper_strategy_string())?;
+        writeln!(f, "║  Game Value: {:<49}║",
+            format!("{:.1}% expected goal rate", self.goal_probability * 100.0))?;
+        write!(f, "╚════════════════════════════════════════════════════════════╝")
+    }
+}
+
 /// Penalty kick game analyzer.
 ///
 /// Models penalty kicks as a two-player zero-sum game and finds
@@ -86,31 +287,53 @@ impl PenaltyAnalysis {
 #[derive(Debug)]
 pub struct PenaltyKick {
     payoff_matrix: PayoffMatrix,
+    payoff_transform: PayoffTransform,
 }
 
 impl PenaltyKick {
     /// Creates a new PK analyzer with the given success rate matrix.
     ///
     /// # Arguments
-    /// * `success_rates` - 3x3 matrix of goal success probabilities
+    /// * `success_rates` - matrix of goal success probabilities
     ///   - Rows: Kicker's direction (Left, Center, Right)
     ///   - Columns: Goalkeeper's dive direction (Left, Center, Right)
     ///   - Values: Probability of scoring (0.0 to 1.0)
-    pub fn new(success_rates: Vec<Vec<f64>>) -> Result<Self, super::payoff::PayoffError> {
-        let row_labels = vec![
-            "Kick Left".into(),
-            "Kick Center".into(),
-            "Kick Right".into(),
-        ];
-        let col_labels = vec![
-            "GK Left".into(),
-            "GK Center".into(),
-            "GK Right".into(),
-        ];
+    ///
+    /// Sides with other than three options get numbered labels
+    /// ("Kick 1", "GK 1", ...); use `analyze_labeled` for such games.
+    pub fn new(success_rates: Vec<Vec<f64>>) -> Result<Self, PayoffError> {
+        let num_cols = success_rates.first().map_or(0, |row| row.len());
+        let row_labels = action_labels("Kick", success_rates.len());
+        let col_labels = action_labels("GK", num_cols);
+
+        let payoff_matrix = PayoffMatrix::new_probabilities(success_rates, row_labels, col_labels)?;
+
+        Ok(Self {
+            payoff_matrix,
+            payoff_transform: PayoffTransform::Symmetric,
+        })
+    }
 
-        let payoff_matrix = PayoffMatrix::new(success_rates, row_labels, col_labels)?;
+    /// Creates a PK analyzer from already validated success rates.
+    ///
+    /// Same layout as `new`; only the matrix shape can still be rejected.
+    pub fn from_probabilities(success_rates: Vec<Vec<Probability>>) -> Result<Self, PayoffError> {
+        let num_cols = success_rates.first().map_or(0, |row| row.len());
+        let row_labels = action_labels("Kick", success_rates.len());
+        let col_labels = action_labels("GK", num_cols);
 
-        Ok(Self { payoff_matrix })
+        Ok(Self {
+            payoff_matrix: PayoffMatrix::from_probabilities(success_rates, row_labels, col_labels)?,
+            payoff_transform: PayoffTransform::Symmetric,
+        })
+    }
+
+    /// Creates a PK analyzer from raw kick records.
+    ///
+    /// Records are converted with `records_to_matrix`, so they must cover
+    /// the 3x3 direction grid.
+    pub fn from_records(records: &[PkRecord]) -> Result<Self, AnalysisError> {
+        Ok(Self::new(records_to_matrix(records)?)?)
     }
 
     /// Creates a PK analyzer with default success rates based on real data.
@@ -128,42 +351,516 @@ impl PenaltyKick {
         Self::new(success_rates).expect("Default data should be valid")
     }
 
+    /// Creates a PK analyzer whose keeper may also stay put.
+    ///
+    /// `success_rates` has three kick rows and four keeper columns: Left,
+    /// Center and Right dives followed by "Stay", a keeper who does not
+    /// commit. Analyze it with `analyze_labeled`; `analyze` keys strategies
+    /// by `Direction` and rejects this shape.
+    pub fn with_stay_option(success_rates: Vec<Vec<f64>>) -> Result<Self, PayoffError> {
+        if success_rates.len() != 3 || success_rates.iter().any(|row| row.len() != 4) {
+            return Err(PayoffError::DimensionMismatch);
+        }
+
+        let row_labels = action_labels("Kick", 3);
+        let mut col_labels = action_labels("GK", 3);
+        col_labels.push("GK Stay".to_string());
+
+        Ok(Self {
+            payoff_matrix: PayoffMatrix::new_probabilities(success_rates, row_labels, col_labels)?,
+            payoff_transform: PayoffTransform::Symmetric,
+        })
+    }
+
+    /// Creates a PK analyzer with the default data plus a "Stay" keeper column.
+    ///
+    /// A keeper who stays reacts late to kicks placed wide but covers the
+    /// middle better than one diving center.
+    pub fn with_default_data_extended() -> Self {
+        // GK actions: Left, Center, Right, Stay
+        let success_rates = vec![
+            vec![0.58, 0.93, 0.95, 0.88], // Kick Left
+            vec![0.83, 0.44, 0.83, 0.35], // Kick Center
+            vec![0.93, 0.90, 0.60, 0.86], // Kick Right
+        ];
+
+        Self::with_stay_option(success_rates).expect("Default data should be valid")
+    }
+
+    /// Creates a PK analyzer whose kicker may also feint.
+    ///
+    /// `success_rates` has four kick rows and three keeper columns: Left,
+    /// Center and Right kicks followed by "Feint", a stutter in the run-up
+    /// that only pays off if the keeper commits early to a side. Analyze it
+    /// with `analyze_labeled`; `analyze` keys strategies by `Direction` and
+    /// rejects this shape.
+    pub fn with_feint_option(success_rates: Vec<Vec<f64>>) -> Result<Self, PayoffError> {
+        if success_rates.len() != 4 || success_rates.iter().any(|row| row.len() != 3) {
+            return Err(PayoffError::DimensionMismatch);
+        }
+
+        let mut row_labels = action_labels("Kick", 3);
+        row_labels.push("Kick Feint".to_string());
+        let col_labels = action_labels("GK", 3);
+
+        Ok(Self {
+            payoff_matrix: PayoffMatrix::new_probabilities(success_rates, row_labels, col_labels)?,
+            payoff_transform: PayoffTransform::Symmetric,
+        })
+    }
+
+    /// Creates a PK analyzer with the default data plus a "Feint" kick row.
+    ///
+    /// A feint converts well against a keeper who dives to either side, but
+    /// the slowed kick is easy to save for a keeper who holds the center.
+    pub fn with_feint_data() -> Self {
+        // GK dives: Left, Center, Right
+        let success_rates = vec![
+            vec![0.58, 0.93, 0.95], // Kick Left
+            vec![0.83, 0.44, 0.83], // Kick Center
+            vec![0.93, 0.90, 0.60], // Kick Right
+            vec![0.88, 0.35, 0.88], // Kick Feint
+        ];
+
+        Self::with_feint_option(success_rates).expect("Default data should be valid")
+    }
+
+    /// Returns the left-right mirror image of this model.
+    ///
+    /// The Left and Right kick rows and dive columns are swapped, so success
+    /// rates measured for a right-footed kicker describe a left-footed one
+    /// and vice versa. Labels stay with their positions, so "Kick Left" now
+    /// holds the old "Kick Right" rates. Center and any extra actions, such
+    /// as a Stay column, are unchanged; sides with fewer than three actions
+    /// are left as they are.
+    pub fn mirror(&self) -> PenaltyKick {
+        let (left, right) = (Direction::Left.index(), Direction::Right.index());
+        let swap = |index: usize, count: usize| match index {
+            i if count > right && i == left => right,
+            i if count > right && i == right => left,
+            i => i,
+        };
+
+        let source = self.payoff_matrix.matrix();
+        let num_rows = self.payoff_matrix.num_rows();
+        let num_cols = self.payoff_matrix.num_cols();
+        let matrix = (0..num_rows)
+            .map(|i| (0..num_cols).map(|j| source[swap(i, num_rows)][swap(j, num_cols)]).collect())
+            .collect();
+
+        let payoff_matrix = PayoffMatrix::new(
+            matrix,
+            self.payoff_matrix.row_labels().to_vec(),
+            self.payoff_matrix.col_labels().to_vec(),
+        )
+        .expect("Mirroring preserves the matrix shape");
+
+        PenaltyKick {
+            payoff_matrix,
+            payoff_transform: self.payoff_transform,
+        }
+    }
+
+    /// Converts a model measured for a `from`-footed kicker to a `to`-footed one.
+    pub fn for_foot(&self, from: Foot, to: Foot) -> PenaltyKick {
+        if from == to {
+            PenaltyKick {
+                payoff_matrix: self.payoff_matrix.clone(),
+                payoff_transform: self.payoff_transform,
+            }
+        } else {
+            self.mirror()
+        }
+    }
+
+    /// Returns a copy of this model with one success rate replaced.
+    ///
+    /// Labels and the payoff transform carry over. Fails with
+    /// `DimensionMismatch` if the cell is out of range and
+    /// `InvalidProbability` if `new_rate` is outside [0, 1].
+    pub fn with_modified_cell(&self, row: usize, col: usize, new_rate: f64) -> Result<PenaltyKick, PayoffError> {
+        if row >= self.payoff_matrix.num_rows() || col >= self.payoff_matrix.num_cols() {
+            return Err(PayoffError::DimensionMismatch);
+        }
+
+        let mut matrix = self.payoff_matrix.matrix().clone();
+        matrix[row][col] = new_rate;
+
+        Ok(PenaltyKick {
+            payoff_matrix: PayoffMatrix::new_probabilities(
+                matrix,
+                self.payoff_matrix.row_labels().to_vec(),
+                self.payoff_matrix.col_labels().to_vec(),
+            )?,
+            payoff_transform: self.payoff_transform,
+        })
+    }
+
+    /// Sets the transform from goal probability to kicker payoff.
+    ///
+    /// Defaults to goal = +1, save = -1. For affine transforms the goal
+    /// probability is recovered by inverting the transform; for `Custom`
+    /// ones it is computed from the equilibrium strategies.
+    pub fn with_payoff_transform(mut self, transform: PayoffTransform) -> Self {
+        self.payoff_transform = transform;
+        self
+    }
+
+    /// Analyzes the game with the goalkeeper maximizing the save rate.
+    ///
+    /// The equilibrium is the same as `analyze`, but it is reported from the
+    /// keeper's side, with `save_probability` in place of the goal rate. Save
+    /// rates are used directly as payoffs, so the payoff transform is not
+    /// applied. Like `analyze`, it requires a 3×3 model.
+    pub fn analyze_save_perspective(&self) -> Result<SaveAnalysis, GameError> {
+        self.check_direction_shape()?;
+        let save_rates: Vec<Vec<f64>> = self
+            .payoff_matrix
+            .matrix()
+            .iter()
+            .map(|row| row.iter().map(|rate| 1.0 - rate).collect())
+            .collect();
+
+        let solution = GameSolver::new_col_maximizer(save_rates.clone())?.solve()?;
+        let payoff_matrix = PayoffMatrix::new(
+            save_rates,
+            self.payoff_matrix.row_labels().to_vec(),
+            self.payoff_matrix.col_labels().to_vec(),
+        )
+        .expect("Save rates keep the matrix shape");
+
+        Ok(SaveAnalysis {
+            goalkeeper_strategy: by_direction(&clamp_probabilities(&solution.col_strategy)),
+            kicker_strategy: by_direction(&clamp_probabilities(&solution.row_strategy)),
+            save_probability: solution.game_value,
+            payoff_matrix,
+        })
+    }
+
     /// Analyzes the penalty kick scenario and returns optimal strategies.
+    ///
+    /// Strategies are reported per `Direction`, so the model must be 3×3;
+    /// other shapes fail with `UnsupportedShape` and should use
+    /// `analyze_labeled`.
     pub fn analyze(&self) -> Result<PenaltyAnalysis, GameError> {
-        // Convert success probabilities to expected payoffs
-        // For kicker: goal = +1, save = -1
-        let payoff_values = self.payoff_matrix.to_expected_payoff();
+        self.check_direction_shape()?;
+        let solution = self.solve()?;
 
-        let solver = GameSolver::new(payoff_values)?;
-        let solution: GameSolution = solver.solve()?;
+        // Convert game value back to probability
+        let goal_probability = self.goal_probability(&solution);
+
+        Ok(PenaltyAnalysis {
+            kicker_strategy: by_direction(&solution.row_strategy),
+            goalkeeper_strategy: by_direction(&solution.col_strategy),
+            goal_probability,
+            payoff_matrix: self.payoff_matrix.clone(),
+        })
+    }
+
+    /// Finds a kicker strategy that trades goal probability for lower variance.
+    ///
+    /// Against the equilibrium keeper, the kicker maximizes
+    /// `P - lambda * P * (1 - P)`, where `P` is the goal probability and
+    /// `P * (1 - P)` the variance of the goal indicator. The objective is
+    /// maximized by projected gradient ascent over the simplex starting from
+    /// the Nash strategy, so `lambda = 0` reproduces `analyze`.
+    pub fn analyze_risk_averse(&self, lambda: f64) -> Result<PenaltyAnalysis, GameError> {
+        self.check_direction_shape()?;
+        let solution = self.solve()?;
+        let keeper = &solution.col_strategy;
+
+        // Goal probability of each kick direction against the fixed keeper
+        let row_probs: Vec<f64> = self
+            .payoff_matrix
+            .matrix()
+            .iter()
+            .map(|row| row.iter().zip(keeper).map(|(m, q)| m * q).sum())
+            .collect();
+        let goal_prob = |x: &[f64]| x.iter().zip(&row_probs).map(|(p, r)| p * r).sum::<f64>();
+        let objective = |x: &[f64]| {
+            let p = goal_prob(x);
+            p - lambda * p * (1.0 - p)
+        };
+
+        let mut kicker = solution.row_strategy.clone();
+        let mut value = objective(&kicker);
+        let mut step = 0.1;
+
+        for _ in 0..RISK_ASCENT_ITERATIONS {
+            if step < 1e-12 {
+                break;
+            }
+
+            // d/dx_i of the objective, through P
+            let p = goal_prob(&kicker);
+            let slope = 1.0 - lambda + 2.0 * lambda * p;
+            let moved: Vec<f64> = kicker
+                .iter()
+                .zip(&row_probs)
+                .map(|(x, r)| x + step * slope * r)
+                .collect();
+            let candidate = project_to_simplex(&moved);
+            let candidate_value = objective(&candidate);
+
+            if candidate_value > value + 1e-15 {
+                kicker = candidate;
+                value = candidate_value;
+            } else {
+                step /= 2.0;
+            }
+        }
+
+        Ok(PenaltyAnalysis {
+            kicker_strategy: by_direction(&kicker),
+            goalkeeper_strategy: by_direction(keeper),
+            goal_probability: goal_prob(&kicker),
+            payoff_matrix: self.payoff_matrix.clone(),
+        })
+    }
 
-        // Convert raw strategies to Direction-probability pairs
-        let kicker_strategy: Vec<(Direction, f64)> = solution
-            .row_strategy
+    /// Finds minimax-regret strategies for both players.
+    ///
+    /// The kicker minimizes the worst-case shortfall from the best kick
+    /// against each dive; the keeper minimizes the worst-case excess over the
+    /// best dive against each kick. A decision-theoretic alternative to
+    /// `analyze` when the opponent model is uncertain.
+    pub fn analyze_minimax_regret(&self) -> Result<PenaltyAnalysis, GameError> {
+        self.check_direction_shape()?;
+        let payoff_values = self.payoff_matrix.to_expected_payoff_with(&self.payoff_transform);
+
+        // The keeper's regret game is the kicker's on the negated transpose
+        let keeper_view: Vec<Vec<f64>> = (0..self.payoff_matrix.num_cols())
+            .map(|j| payoff_values.iter().map(|row| -row[j]).collect())
+            .collect();
+
+        let kicker = GameSolver::new(payoff_values)?.minimax_regret()?;
+        let keeper = GameSolver::new(keeper_view)?.minimax_regret()?;
+
+        Ok(PenaltyAnalysis {
+            kicker_strategy: by_direction(&kicker),
+            goalkeeper_strategy: by_direction(&keeper),
+            goal_probability: self.expected_goal_probability(&kicker, &keeper),
+            payoff_matrix: self.payoff_matrix.clone(),
+        })
+    }
+
+    /// Returns the weakly dominated (kick, dive) directions.
+    ///
+    /// A kick direction is weakly dominated if another one scores at least
+    /// as often against every dive and more often against some; dives are
+    /// judged the same way from the keeper's side. The matrix is unchanged.
+    /// Requires a 3×3 model.
+    pub fn dominated_directions(&self) -> Result<(Vec<Direction>, Vec<Direction>), GameError> {
+        self.check_direction_shape()?;
+        let solver = GameSolver::new(self.payoff_matrix.to_expected_payoff_with(&self.payoff_transform))?;
+        let to_directions =
+            |indices: Vec<usize>| indices.into_iter().filter_map(Direction::from_index).collect();
+
+        Ok((
+            to_directions(solver.weakly_dominated_rows()),
+            to_directions(solver.weakly_dominated_cols()),
+        ))
+    }
+
+    /// Returns the goal-probability loss of always kicking each row.
+    ///
+    /// Each kick row, by index, is played as a pure strategy against the
+    /// equilibrium keeper and compared with the equilibrium goal
+    /// probability. Rows in the equilibrium support have (near) zero regret.
+    pub fn pure_strategy_regret(&self) -> Result<Vec<(usize, f64)>, GameError> {
+        let solution = self.solve()?;
+        let goal_probability = self.goal_probability(&solution);
+        let num_rows = self.payoff_matrix.num_rows();
+
+        Ok((0..num_rows)
+            .map(|i| {
+                let mut pure = vec![0.0; num_rows];
+                pure[i] = 1.0;
+                (i, goal_probability - self.expected_goal_probability(&pure, &solution.col_strategy))
+            })
+            .collect())
+    }
+
+    /// Returns the kick row scoring most often against a known keeper
+    /// distribution, with its goal probability.
+    ///
+    /// Ties go to the lowest row index.
+    pub fn best_response_kicker(&self, gk_strategy: &[f64]) -> (usize, f64) {
+        self.payoff_matrix
+            .matrix()
             .iter()
+            .map(|row| row.iter().zip(gk_strategy).map(|(m, q)| m * q).sum::<f64>())
             .enumerate()
-            .filter_map(|(i, &prob)| Direction::from_index(i).map(|d| (d, prob)))
+            .fold((0, f64::NEG_INFINITY), |best, candidate| {
+                if candidate.1 > best.1 { candidate } else { best }
+            })
+    }
+
+    /// Returns the keeper's least-bad pure dive column against the
+    /// equilibrium kicker, with the goal probability it concedes.
+    ///
+    /// Every dive in the keeper's equilibrium support concedes exactly the
+    /// game value, so ties within 1e-9 are broken toward the lowest column
+    /// index (Left, then Center, then Right in the 3×3 model).
+    pub fn best_gk_dive_against_equilibrium(&self) -> Result<(usize, f64), GameError> {
+        let kicker = self.solve()?.row_strategy;
+        let matrix = self.payoff_matrix.matrix();
+
+        let conceded: Vec<(usize, f64)> = (0..self.payoff_matrix.num_cols())
+            .map(|j| {
+                let prob: f64 = matrix.iter().zip(&kicker).map(|(row, p)| row[j] * p).sum();
+                (j, prob)
+            })
             .collect();
+        let least = conceded.iter().map(|(_, prob)| *prob).fold(f64::INFINITY, f64::min);
+
+        Ok(conceded
+            .into_iter()
+            .find(|(_, prob)| *prob <= least + 1e-9)
+            .expect("at least one direction"))
+    }
+
+    /// Scores candidate kicker strategies against the equilibrium keeper.
+    ///
+    /// Returns `(candidate index, goal probability)` pairs, best first; ties
+    /// keep their input order. Every strategy mixing only over the kicker's
+    /// equilibrium support ties with the equilibrium itself, since the
+    /// keeper's mix leaves the kicker indifferent among those directions.
+    /// Candidates scoring NaN, e.g. from NaN probabilities, are ranked last.
+    pub fn rank_strategies(&self, candidates: &[Vec<f64>]) -> Result<Vec<(usize, f64)>, GameError> {
+        let keeper = self.solve()?.col_strategy;
 
-        let goalkeeper_strategy: Vec<(Direction, f64)> = solution
-            .col_strategy
+        let mut ranked: Vec<(usize, f64)> = candidates
             .iter()
             .enumerate()
-            .filter_map(|(i, &prob)| Direction::from_index(i).map(|d| (d, prob)))
+            .map(|(i, candidate)| (i, self.expected_goal_probability(candidate, &keeper)))
             .collect();
+        ranked.sort_by(|a, b| a.1.is_nan().cmp(&b.1.is_nan()).then(b.1.total_cmp(&a.1)));
 
-        // Convert game value back to probability
-        // Game value is in [-1, 1], convert back to [0, 1]
-        let goal_probability = (solution.game_value + 1.0) / 2.0;
+        Ok(ranked)
+    }
 
-        Ok(PenaltyAnalysis {
-            kicker_strategy,
-            goalkeeper_strategy,
+    /// Goal-probability gain from knowing the keeper's strategy.
+    ///
+    /// Compares best-responding to `gk_strategy` with playing the
+    /// equilibrium kicker strategy against it. Zero against the equilibrium
+    /// keeper, positive against any exploitable one.
+    pub fn value_of_scouting(&self, gk_strategy: &[f64]) -> Result<f64, GameError> {
+        let equilibrium = self.solve()?;
+        let (_, best) = self.best_response_kicker(gk_strategy);
+        let baseline = self.expected_goal_probability(&equilibrium.row_strategy, gk_strategy);
+        Ok(best - baseline)
+    }
+
+    /// Goal-probability gain from best-responding to the equilibrium keeper.
+    ///
+    /// At an exact equilibrium no kick beats the equilibrium goal rate, so
+    /// the gap is zero up to solver error; a noticeably positive value means
+    /// the solution is numerically off.
+    pub fn efficiency_gap(&self) -> Result<f64, GameError> {
+        let solution = self.solve()?;
+        let (_, best) = self.best_response_kicker(&solution.col_strategy);
+        Ok(best - self.goal_probability(&solution))
+    }
+
+    /// Returns the highest success rate in the matrix.
+    ///
+    /// This is the kicker's ceiling if the keeper's dive were known and
+    /// fixed to the most favorable one.
+    pub fn best_possible_goal_rate(&self) -> f64 {
+        self.payoff_matrix
+            .matrix()
+            .iter()
+            .flatten()
+            .cloned()
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    /// Goal-probability gain of the equilibrium mix over the best pure kick.
+    ///
+    /// The pure baseline is the kick whose worst case is best, i.e. the goal
+    /// rate when the keeper best-responds to a known direction. Rounding
+    /// below zero is clamped, so the result is zero at a saddle point.
+    pub fn value_of_mixing(&self) -> Result<f64, GameError> {
+        let solution = self.solve()?;
+        let best_pure = self
+            .payoff_matrix
+            .matrix()
+            .iter()
+            .map(|row| row.iter().cloned().fold(f64::INFINITY, f64::min))
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        Ok((self.goal_probability(&solution) - best_pure).max(0.0))
+    }
+
+    /// KL divergence in bits of a kicker's habitual mix from the equilibrium.
+    ///
+    /// See `strategy_kl`; infinite if `actual` uses a direction the
+    /// equilibrium never kicks.
+    pub fn kicker_divergence(&self, actual: &[f64]) -> Result<f64, GameError> {
+        let solution = self.solve()?;
+        Ok(strategy_kl(actual, &solution.row_strategy))
+    }
+
+    /// Analyzes a game of any shape, labeling strategies by matrix labels.
+    pub fn analyze_labeled(&self) -> Result<LabeledAnalysis, GameError> {
+        let solution = self.solve()?;
+        let goal_probability = self.goal_probability(&solution);
+
+        let label = |labels: &[String], strategy: Vec<f64>| -> Vec<(String, f64)> {
+            labels.iter().cloned().zip(strategy).collect()
+        };
+
+        Ok(LabeledAnalysis {
+            kicker_strategy: label(self.payoff_matrix.row_labels(), solution.row_strategy),
+            goalkeeper_strategy: label(self.payoff_matrix.col_labels(), solution.col_strategy),
             goal_probability,
-            payoff_matrix: self.payoff_matrix.clone(),
         })
     }
 
+    /// Solves the zero-sum game on the kicker's expected payoffs.
+    fn solve(&self) -> Result<GameSolution, GameError> {
+        let payoff_values = self.payoff_matrix.to_expected_payoff_with(&self.payoff_transform);
+
+        let solver = GameSolver::new(payoff_values)?;
+        let mut solution = solver.solve()?;
+
+        // Pivoting can leave components like -1e-17 that must not reach callers
+        solution.row_strategy = clamp_probabilities(&solution.row_strategy);
+        solution.col_strategy = clamp_probabilities(&solution.col_strategy);
+        Ok(solution)
+    }
+
+    /// Converts an equilibrium back to the kicker's goal probability.
+    fn goal_probability(&self, solution: &GameSolution) -> f64 {
+        match self.payoff_transform.probability(solution.game_value) {
+            Some(probability) => probability,
+            None => self.expected_goal_probability(&solution.row_strategy, &solution.col_strategy),
+        }
+    }
+
+    /// Iterates over every pure `(kick, dive, goal probability)` outcome.
+    ///
+    /// Actions are named by the matrix labels, so extra options such as the
+    /// keeper staying put in `with_stay_option` games are included.
+    pub fn outcomes(&self) -> impl Iterator<Item = (&str, &str, f64)> + '_ {
+        let rows = self.payoff_matrix.row_labels();
+        let cols = self.payoff_matrix.col_labels();
+        self.payoff_matrix
+            .outcomes()
+            .map(move |(i, j, rate)| (rows[i].as_str(), cols[j].as_str(), rate))
+    }
+
+    /// Fails unless the model has one kick row and one dive column per `Direction`.
+    fn check_direction_shape(&self) -> Result<(), GameError> {
+        let (rows, cols) = (self.payoff_matrix.num_rows(), self.payoff_matrix.num_cols());
+        if rows == Direction::all().len() && cols == Direction::all().len() {
+            Ok(())
+        } else {
+            Err(GameError::UnsupportedShape { rows, cols })
+        }
+    }
+
     /// Returns the payoff matrix.
     pub fn payoff_matrix(&self) -> &PayoffMatrix {
         &self.payoff_matrix
@@ -188,6 +885,86 @@ impl PenaltyKick {
     }
 }
 
+/// Maximum gradient steps in `analyze_risk_averse`.
+const RISK_ASCENT_ITERATIONS: usize = 2000;
+
+/// Directions whose probability exceeds `threshold`.
+fn support(strategy: &[(Direction, f64)], threshold: f64) -> Vec<Direction> {
+    strategy
+        .iter()
+        .filter(|(_, prob)| *prob > threshold)
+        .map(|(dir, _)| *dir)
+        .collect()
+}
+
+/// Shannon entropy in bits, treating `0 log 0` as 0.
+fn entropy(strategy: &[(Direction, f64)]) -> f64 {
+    strategy
+        .iter()
+        .filter(|(_, prob)| *prob > 0.0)
+        .map(|(_, prob)| -prob * prob.log2())
+        .sum()
+}
+
+/// Pairs the entries of a three-action strategy with their `Direction`.
+///
+/// Callers check the model shape first, so no entry is dropped.
+fn by_direction(strategy: &[f64]) -> Vec<(Direction, f64)> {
+    debug_assert_eq!(strategy.len(), Direction::all().len());
+    strategy
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &prob)| Direction::from_index(i).map(|d| (d, prob)))
+        .collect()
+}
+
+/// Clamps rounding error out of a solver strategy and renormalizes it.
+///
+/// Components may be off by up to the tolerance `GameSolution::verify`
+/// accepts, since the solver has already validated the strategy with it.
+fn clamp_probabilities(strategy: &[f64]) -> Vec<f64> {
+    debug_assert!(
+        strategy.iter().all(|p| (-VERIFY_TOLERANCE..=1.0 + VERIFY_TOLERANCE).contains(p)),
+        "strategy {:?} is outside [0, 1] by more than rounding error",
+        strategy
+    );
+
+    let clamped: Vec<f64> = strategy.iter().map(|p| p.clamp(0.0, 1.0)).collect();
+    let sum: f64 = clamped.iter().sum();
+    clamped.iter().map(|p| p / sum).collect()
+}
+
+/// Euclidean projection onto the probability simplex.
+fn project_to_simplex(v: &[f64]) -> Vec<f64> {
+    let mut sorted = v.to_vec();
+    sorted.sort_by(|a, b| b.total_cmp(a));
+
+    let mut cumulative = 0.0;
+    let mut theta = 0.0;
+    for (k, &u) in sorted.iter().enumerate() {
+        cumulative += u;
+        let candidate = (cumulative - 1.0) / (k + 1) as f64;
+        if u > candidate {
+            theta = candidate;
+        }
+    }
+
+    v.iter().map(|&x| (x - theta).max(0.0)).collect()
+}
+
+/// Builds labels for one side: direction names for three options,
+/// numbered otherwise.
+fn action_labels(prefix: &str, count: usize) -> Vec<String> {
+    if count == Direction::all().len() {
+        Direction::all()
+            .iter()
+            .map(|d| format!("{} {}", prefix, d.name()))
+            .collect()
+    } else {
+        (1..=count).map(|i| format!("{} {}", prefix, i)).collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -222,6 +999,549 @@ mod tests {
         assert!(analysis.goal_probability > 0.5);
     }
 
+    #[test]
+    fn test_empty_matrix_rejected() {
+        use crate::football::payoff::PayoffError;
+
+        assert!(matches!(PenaltyKick::new(vec![]), Err(PayoffError::EmptyMatrix)));
+        assert!(matches!(
+            PenaltyKick::new(vec![vec![], vec![], vec![]]),
+            Err(PayoffError::EmptyMatrix)
+        ));
+    }
+
+    #[test]
+    fn test_out_of_range_success_rate_rejected() {
+        let result = PenaltyKick::new(vec![
+            vec![0.58, 0.93, 0.95],
+            vec![0.83, 1.3, 0.83],
+            vec![0.93, 0.90, 0.60],
+        ]);
+
+        assert!(matches!(
+            result,
+            Err(crate::football::payoff::PayoffError::InvalidProbability(p)) if p == 1.3
+        ));
+    }
+
+    #[test]
+    fn test_affine_transform_goal_probability() {
+        let transform = PayoffTransform::affine(3.0, 0.0).unwrap();
+        let pk = PenaltyKick::with_default_data().with_payoff_transform(transform);
+        let analysis = pk.analyze().unwrap();
+
+        let kicker: Vec<f64> = analysis.kicker_strategy.iter().map(|(_, p)| *p).collect();
+        let gk: Vec<f64> = analysis.goalkeeper_strategy.iter().map(|(_, p)| *p).collect();
+        let direct = pk.expected_goal_probability(&kicker, &gk);
+
+        assert_relative_eq!(analysis.goal_probability, direct, epsilon = 1e-9);
+        assert_relative_eq!(
+            analysis.goal_probability,
+            PenaltyKick::with_default_data().analyze().unwrap().goal_probability,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_risk_averse_reduces_variance() {
+        let pk = PenaltyKick::with_default_data();
+        let standard = pk.analyze().unwrap();
+        let neutral = pk.analyze_risk_averse(0.0).unwrap();
+        assert_relative_eq!(neutral.goal_probability, standard.goal_probability, epsilon = 1e-12);
+        for (a, b) in neutral.kicker_strategy.iter().zip(&standard.kicker_strategy) {
+            assert_relative_eq!(a.1, b.1, epsilon = 1e-12);
+        }
+
+        // A weak kicker with a low-variance fallback: Right rarely scores
+        let pk = PenaltyKick::new(vec![
+            vec![0.20, 0.50, 0.45],
+            vec![0.45, 0.25, 0.40],
+            vec![0.10, 0.10, 0.10],
+        ])
+        .unwrap();
+
+        let variances: Vec<f64> = [0.0, 0.5, 1.0, 2.0, 5.0]
+            .iter()
+            .map(|&lambda| {
+                let p = pk.analyze_risk_averse(lambda).unwrap().goal_probability;
+                p * (1.0 - p)
+            })
+            .collect();
+
+        assert!(variances.windows(2).all(|w| w[1] <= w[0] + 1e-9), "{:?}", variances);
+        assert!(variances[4] < variances[0]);
+    }
+
+    #[test]
+    fn test_strategy_support() {
+        let analysis = PenaltyKick::with_default_data().analyze().unwrap();
+
+        assert_eq!(analysis.kicker_support(0.01), Direction::all());
+        assert_eq!(analysis.goalkeeper_support(0.01), Direction::all());
+        assert!(!analysis.is_pure());
+        assert!(analysis.kicker_support(0.99).is_empty());
+    }
+
+    #[test]
+    fn test_strategy_entropy() {
+        let analysis = PenaltyAnalysis {
+            kicker_strategy: by_direction(&[1.0 / 3.0; 3]),
+            goalkeeper_strategy: by_direction(&[0.0, 1.0, 0.0]),
+            goal_probability: 0.5,
+            payoff_matrix: PenaltyKick::with_default_data().payoff_matrix().clone(),
+        };
+
+        assert_relative_eq!(analysis.kicker_entropy(), 3f64.log2(), epsilon = 1e-12);
+        assert_eq!(analysis.goalkeeper_entropy(), 0.0);
+    }
+
+    #[test]
+    fn test_symmetric_equilibrium() {
+        assert!(!PenaltyKick::with_default_data().analyze().unwrap().is_symmetric_equilibrium(0.01));
+
+        // Scoring unless the keeper guesses right: both players mix uniformly
+        let guessing = vec![vec![0.0, 1.0, 1.0], vec![1.0, 0.0, 1.0], vec![1.0, 1.0, 0.0]];
+        let analysis = PenaltyKick::new(guessing).unwrap().analyze().unwrap();
+        assert!(analysis.is_symmetric_equilibrium(1e-9));
+    }
+
+    #[test]
+    fn test_rounding_never_yields_negative_probabilities() {
+        // The raw LP leaves the keeper's Left component around -9e-17 here
+        let rates = vec![vec![0.14, 0.31, 0.39], vec![0.83, 0.87, 0.39], vec![0.53, 0.09, 0.34]];
+        let pk = PenaltyKick::new(rates).unwrap();
+
+        let analysis = pk.analyze().unwrap();
+        let labeled = pk.analyze_labeled().unwrap();
+
+        for (_, p) in analysis.kicker_strategy.iter().chain(&analysis.goalkeeper_strategy) {
+            assert!(*p >= 0.0, "{:?}", analysis);
+        }
+        for (_, p) in labeled.kicker_strategy.iter().chain(&labeled.goalkeeper_strategy) {
+            assert!(*p >= 0.0, "{:?}", labeled);
+        }
+    }
+
+    #[test]
+    fn test_direction_display_and_parse() {
+        assert_eq!("left".parse::<Direction>().unwrap(), Direction::Left);
+        assert_eq!("Centre".parse::<Direction>().unwrap(), Direction::Center);
+        assert_eq!("r".parse::<Direction>().unwrap(), Direction::Right);
+        assert!("top".parse::<Direction>().is_err());
+
+        let dir = Direction::Center;
+        assert_eq!(format!("{dir}"), "Center");
+        assert_eq!(format!("{:>7}", Direction::Left), "   Left");
+    }
+
+    #[test]
+    fn test_analysis_display() {
+        let analysis = PenaltyKick::with_default_data().analyze().unwrap();
+        let summary = analysis.to_string();
+
+        assert!(summary.contains("NASH EQUILIBRIUM"));
+        assert!(summary.contains(&analysis.kicker_strategy_string()));
+        assert!(summary.contains(&format!("{:.1}% expected goal rate", analysis.goal_probability * 100.0)));
+    }
+
+    #[test]
+    fn test_best_gk_dive_against_equilibrium() {
+        let pk = PenaltyKick::with_default_data();
+        let analysis = pk.analyze().unwrap();
+
+        let (dive, prob) = pk.best_gk_dive_against_equilibrium().unwrap();
+
+        // The keeper mixes over all three dives, so they tie and Left wins
+        assert_eq!(dive, Direction::Left.index());
+        assert_relative_eq!(prob, analysis.goal_probability, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_rank_strategies() {
+        let pk = PenaltyKick::with_default_data();
+        let equilibrium: Vec<f64> = pk.analyze().unwrap().kicker_strategy.iter().map(|(_, p)| *p).collect();
+        let candidates = vec![
+            vec![0.0, 1.0, 0.0],
+            vec![1.0 / 3.0; 3],
+            equilibrium,
+            vec![0.5, 0.0, 0.5],
+        ];
+
+        let ranked = pk.rank_strategies(&candidates).unwrap();
+
+        assert_eq!(ranked.len(), 4);
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+        let score = |index: usize| ranked.iter().find(|(i, _)| *i == index).unwrap().1;
+        assert!(score(2) >= score(1) - 1e-9);
+        assert!(score(2) >= score(3) - 1e-9);
+
+        let with_nan = pk.rank_strategies(&[vec![f64::NAN, 0.5, 0.5], vec![0.0, 1.0, 0.0]]).unwrap();
+        assert_eq!(with_nan[0].0, 1);
+        assert!(with_nan[1].1.is_nan());
+    }
+
+    #[test]
+    fn test_outcomes() {
+        let pk = PenaltyKick::with_default_data();
+
+        assert_eq!(pk.outcomes().count(), 9);
+        assert!(pk.outcomes().any(|outcome| outcome == ("Kick Left", "GK Right", 0.95)));
+
+        let extended = PenaltyKick::with_default_data_extended();
+        assert_eq!(extended.outcomes().count(), 12);
+        assert!(extended.outcomes().any(|outcome| outcome == ("Kick Center", "GK Stay", 0.35)));
+    }
+
+    #[test]
+    fn test_dominated_directions() {
+        // Kicking center is never better than kicking left
+        let pk = PenaltyKick::new(vec![
+            vec![0.60, 0.90, 0.95],
+            vec![0.60, 0.45, 0.80],
+            vec![0.93, 0.90, 0.60],
+        ])
+        .unwrap();
+
+        let (kicks, dives) = pk.dominated_directions().unwrap();
+        assert_eq!(kicks, vec![Direction::Center]);
+        assert!(dives.is_empty());
+
+        let (kicks, _) = PenaltyKick::with_default_data().dominated_directions().unwrap();
+        assert!(kicks.is_empty());
+    }
+
+    #[test]
+    fn test_pure_strategy_regret() {
+        let regrets = PenaltyKick::with_default_data().pure_strategy_regret().unwrap();
+        for (_, regret) in &regrets {
+            assert_relative_eq!(*regret, 0.0, epsilon = 1e-9);
+        }
+
+        // Center is strictly dominated by Left and never played
+        let pk = PenaltyKick::new(vec![
+            vec![0.60, 0.90, 0.95],
+            vec![0.55, 0.40, 0.75],
+            vec![0.93, 0.90, 0.60],
+        ])
+        .unwrap();
+        let regrets = pk.pure_strategy_regret().unwrap();
+
+        assert_relative_eq!(regrets[0].1, 0.0, epsilon = 1e-9);
+        assert!(regrets[1].1 > 0.05);
+        assert_relative_eq!(regrets[2].1, 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_minimax_regret_analysis() {
+        let pk = PenaltyKick::with_default_data();
+        let nash = pk.analyze().unwrap();
+        let regret = pk.analyze_minimax_regret().unwrap();
+
+        let kicker_sum: f64 = regret.kicker_strategy.iter().map(|(_, p)| p).sum();
+        let keeper_sum: f64 = regret.goalkeeper_strategy.iter().map(|(_, p)| p).sum();
+        assert_relative_eq!(kicker_sum, 1.0, epsilon = 1e-9);
+        assert_relative_eq!(keeper_sum, 1.0, epsilon = 1e-9);
+
+        let difference = nash
+            .kicker_strategy
+            .iter()
+            .zip(&regret.kicker_strategy)
+            .map(|((_, a), (_, b))| (a - b).abs())
+            .fold(0.0, f64::max);
+        assert!(difference > 0.01, "strategies should differ, got {:?}", regret.kicker_strategy);
+    }
+
+    #[test]
+    fn test_value_of_scouting() {
+        let pk = PenaltyKick::with_default_data();
+        let analysis = pk.analyze().unwrap();
+        let gk: Vec<f64> = analysis.goalkeeper_strategy.iter().map(|(_, p)| *p).collect();
+
+        assert_relative_eq!(pk.value_of_scouting(&gk).unwrap(), 0.0, epsilon = 1e-9);
+
+        // A keeper who always dives left concedes 0.93 to a kick right
+        let always_left = [1.0, 0.0, 0.0];
+        assert_eq!(pk.best_response_kicker(&always_left), (Direction::Right.index(), 0.93));
+        assert!(pk.value_of_scouting(&always_left).unwrap() > 0.05);
+    }
+
+    #[test]
+    fn test_pure_action_queries_on_2x2() {
+        // Matching pennies shape: both players mix 50/50 for 0.75
+        let pk = PenaltyKick::new(vec![vec![0.6, 0.9], vec![0.9, 0.6]]).unwrap();
+
+        assert_eq!(pk.best_response_kicker(&[1.0, 0.0]), (1, 0.9));
+        let regrets = pk.pure_strategy_regret().unwrap();
+        assert_eq!(regrets.len(), 2);
+        for (_, regret) in regrets {
+            assert_relative_eq!(regret, 0.0, epsilon = 1e-9);
+        }
+        let (dive, prob) = pk.best_gk_dive_against_equilibrium().unwrap();
+        assert_eq!(dive, 0);
+        assert_relative_eq!(prob, 0.75, epsilon = 1e-9);
+        assert_relative_eq!(pk.efficiency_gap().unwrap(), 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_pure_action_queries_cover_extra_actions() {
+        // Against a keeper who never dives center, the feint row scores best
+        let pk = PenaltyKick::with_feint_option(vec![
+            vec![0.58, 0.93, 0.95],
+            vec![0.83, 0.44, 0.83],
+            vec![0.93, 0.90, 0.60],
+            vec![0.97, 0.30, 0.97],
+        ])
+        .unwrap();
+        assert_eq!(pk.best_response_kicker(&[0.5, 0.0, 0.5]), (3, 0.97));
+        assert_eq!(pk.pure_strategy_regret().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_efficiency_gap_and_ceiling() {
+        let pk = PenaltyKick::with_default_data();
+
+        assert_relative_eq!(pk.efficiency_gap().unwrap(), 0.0, epsilon = 1e-9);
+        assert_eq!(pk.best_possible_goal_rate(), 0.95);
+    }
+
+    #[test]
+    fn test_analyze_labeled_non_square() {
+        // Four kick options against a keeper who only dives left or right
+        let pk = PenaltyKick::new(vec![
+            vec![0.60, 0.95],
+            vec![0.85, 0.70],
+            vec![0.90, 0.55],
+            vec![0.75, 0.80],
+        ])
+        .unwrap();
+
+        let analysis = pk.analyze_labeled().unwrap();
+
+        assert_eq!(analysis.kicker_strategy.len(), 4);
+        assert_eq!(analysis.goalkeeper_strategy.len(), 2);
+        assert_eq!(analysis.kicker_strategy[3].0, "Kick 4");
+        let kicker_sum: f64 = analysis.kicker_strategy.iter().map(|(_, p)| p).sum();
+        assert_relative_eq!(kicker_sum, 1.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_extended_data_with_stay() {
+        let pk = PenaltyKick::with_default_data_extended();
+        assert_eq!(pk.payoff_matrix().num_cols(), 4);
+        assert_eq!(pk.payoff_matrix().col_labels()[3], "GK Stay");
+
+        let analysis = pk.analyze_labeled().unwrap();
+        assert_eq!(analysis.goalkeeper_strategy.len(), 4);
+        let keeper_sum: f64 = analysis.goalkeeper_strategy.iter().map(|(_, p)| p).sum();
+        assert_relative_eq!(keeper_sum, 1.0, epsilon = 1e-9);
+
+        assert!(PenaltyKick::with_stay_option(vec![vec![0.5; 3]; 3]).is_err());
+    }
+
+    #[test]
+    fn test_direction_analyses_reject_other_shapes() {
+        // Dropping the Stay or Feint probability would leave strategies summing below 1
+        for pk in [PenaltyKick::with_default_data_extended(), PenaltyKick::with_feint_data()] {
+            let (rows, cols) = (pk.payoff_matrix().num_rows(), pk.payoff_matrix().num_cols());
+            let shape_error = |result: Result<(), GameError>| {
+                matches!(result, Err(GameError::UnsupportedShape { rows: r, cols: c }) if r == rows && c == cols)
+            };
+
+            assert!(shape_error(pk.analyze().map(|_| ())));
+            assert!(shape_error(pk.analyze_risk_averse(0.5).map(|_| ())));
+            assert!(shape_error(pk.analyze_minimax_regret().map(|_| ())));
+            assert!(shape_error(pk.analyze_save_perspective().map(|_| ())));
+            assert!(shape_error(pk.dominated_directions().map(|_| ())));
+            assert!(pk.analyze_labeled().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_feint_data() {
+        let pk = PenaltyKick::with_feint_data();
+        assert_eq!(pk.payoff_matrix().num_rows(), 4);
+        assert_eq!(pk.payoff_matrix().row_labels()[3], "Kick Feint");
+
+        // The default keeper dives wide almost 90% of the time, so feinting pays
+        let analysis = pk.analyze_labeled().unwrap();
+        let (label, feint) = &analysis.kicker_strategy[3];
+        assert_eq!(label, "Kick Feint");
+        assert!(*feint > 0.1, "feint probability {}", feint);
+        assert!(analysis.goal_probability > PenaltyKick::with_default_data().analyze().unwrap().goal_probability);
+
+        assert!(PenaltyKick::with_feint_option(vec![vec![0.5; 3]; 3]).is_err());
+    }
+
+    #[test]
+    fn test_mirror() {
+        // Strong to the kicker's left: high rates in the Kick Left row
+        let pk = PenaltyKick::new(vec![
+            vec![0.70, 0.95, 0.97],
+            vec![0.80, 0.40, 0.80],
+            vec![0.85, 0.85, 0.50],
+        ])
+        .unwrap();
+
+        let twice = pk.mirror().mirror();
+        assert_eq!(twice.payoff_matrix().matrix(), pk.payoff_matrix().matrix());
+
+        let mirrored = pk.for_foot(Foot::Right, Foot::Left);
+        assert_eq!(mirrored.payoff_matrix().row(2), Some(&[0.97, 0.95, 0.70][..]));
+        assert_eq!(mirrored.payoff_matrix().row(0), Some(&[0.50, 0.85, 0.85][..]));
+        assert_eq!(mirrored.payoff_matrix().row_labels()[0], "Kick Left");
+
+        let original = pk.analyze().unwrap();
+        let flipped = mirrored.analyze().unwrap();
+        assert!(original.kicker_strategy[0].1 > original.kicker_strategy[2].1);
+        assert!(flipped.kicker_strategy[2].1 > flipped.kicker_strategy[0].1);
+        assert_relative_eq!(flipped.goal_probability, original.goal_probability, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_analysis_approx_eq() {
+        let analysis = PenaltyKick::with_default_data().analyze().unwrap();
+        let mut perturbed = analysis.clone();
+        perturbed.kicker_strategy.reverse();
+        perturbed.goalkeeper_strategy[0].1 += 1e-6;
+
+        assert!(analysis.approx_eq(&perturbed, 1e-5));
+        assert!(!analysis.approx_eq(&perturbed, 1e-8));
+
+        perturbed.goal_probability += 0.01;
+        assert!(!analysis.approx_eq(&perturbed, 1e-5));
+    }
+
+    #[test]
+    fn test_kicker_divergence() {
+        let pk = PenaltyKick::with_default_data();
+        let equilibrium: Vec<f64> = pk.analyze().unwrap().kicker_strategy.iter().map(|(_, p)| *p).collect();
+
+        assert_relative_eq!(pk.kicker_divergence(&equilibrium).unwrap(), 0.0, epsilon = 1e-9);
+        assert!(pk.kicker_divergence(&[0.0, 0.0, 1.0]).unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_value_of_mixing() {
+        // Kicking right guarantees 0.60; the equilibrium mix reaches ~0.78
+        let value = PenaltyKick::with_default_data().value_of_mixing().unwrap();
+        assert!(value > 0.0);
+        assert_relative_eq!(
+            value,
+            PenaltyKick::with_default_data().analyze().unwrap().goal_probability - 0.60,
+            epsilon = 1e-9
+        );
+
+        // Kick Left vs GK Center is a saddle point: the row minimum and column maximum
+        let saddle = PenaltyKick::new(vec![
+            vec![0.8, 0.6, 0.7],
+            vec![0.5, 0.4, 0.3],
+            vec![0.9, 0.5, 0.6],
+        ])
+        .unwrap();
+        assert_relative_eq!(saddle.value_of_mixing().unwrap(), 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_save_perspective() {
+        let pk = PenaltyKick::with_default_data();
+        let goals = pk.analyze().unwrap();
+        let saves = pk.analyze_save_perspective().unwrap();
+
+        assert_relative_eq!(saves.save_probability + goals.goal_probability, 1.0, epsilon = 1e-9);
+        assert_relative_eq!(saves.payoff_matrix.get(1, 1).unwrap(), 0.56, epsilon = 1e-12);
+        for ((dir, p), (goal_dir, q)) in saves.goalkeeper_strategy.iter().zip(&goals.goalkeeper_strategy) {
+            assert_eq!(dir, goal_dir);
+            assert_relative_eq!(p, q, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_from_probabilities() {
+        let rates = vec![
+            vec![0.58, 0.93, 0.95],
+            vec![0.83, 0.44, 0.83],
+            vec![0.93, 0.90, 0.60],
+        ];
+        let validated: Vec<Vec<Probability>> = rates
+            .iter()
+            .map(|row| row.iter().map(|&p| Probability::new(p).unwrap()).collect())
+            .collect();
+
+        let typed = PenaltyKick::from_probabilities(validated).unwrap().analyze().unwrap();
+        let raw = PenaltyKick::new(rates).unwrap().analyze().unwrap();
+
+        assert_eq!(typed.kicker_strategy, raw.kicker_strategy);
+        assert_relative_eq!(typed.goal_probability, raw.goal_probability, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_gk_action_contribution() {
+        let analysis = PenaltyKick::with_default_data().analyze().unwrap();
+        let contributions = analysis.gk_action_contribution();
+
+        assert_eq!(contributions.len(), 3);
+        for (dir, gain) in contributions {
+            // Every dive is in the default keeper's support
+            assert!(gain.unwrap() > 0.0, "dropping {:?} gains {:?}", dir, gain);
+        }
+
+        // Without center dives, kicking center scores 0.83 against either side
+        let center = analysis.gk_action_contribution()[Direction::Center.index()].1.unwrap();
+        assert_relative_eq!(center, 0.83 - analysis.goal_probability, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_gk_action_contribution_at_saddle_point() {
+        // Kick Left vs GK Center is a saddle point, so the keeper always dives center
+        let analysis = PenaltyKick::new(vec![
+            vec![0.8, 0.6, 0.7],
+            vec![0.5, 0.4, 0.3],
+            vec![0.9, 0.5, 0.6],
+        ])
+        .unwrap()
+        .analyze()
+        .unwrap();
+
+        assert_eq!(
+            analysis.gk_action_contribution(),
+            vec![(Direction::Left, Some(0.0)), (Direction::Center, None), (Direction::Right, Some(0.0))]
+        );
+    }
+
+    #[test]
+    fn test_gk_indifference_payoffs() {
+        let analysis = PenaltyKick::with_default_data().analyze().unwrap();
+        let support = analysis.goalkeeper_support(SUPPORT_THRESHOLD);
+        let payoffs = analysis.gk_indifference_payoffs();
+
+        assert_eq!(payoffs.len(), 3);
+        for (dir, payoff) in payoffs {
+            if support.contains(&dir) {
+                assert_relative_eq!(payoff, analysis.goal_probability, epsilon = 1e-9);
+            } else {
+                assert!(payoff >= analysis.goal_probability - 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_with_modified_cell() {
+        let pk = PenaltyKick::with_default_data();
+        let original = pk.analyze().unwrap();
+
+        // Keeper gets better at saving kicks to the left when diving left
+        let improved = pk.with_modified_cell(0, 0, 0.40).unwrap();
+        let analysis = improved.analyze().unwrap();
+
+        assert_eq!(improved.payoff_matrix().get(0, 0), Some(0.40));
+        assert_eq!(pk.payoff_matrix().get(0, 0), Some(0.58));
+        assert!(analysis.goal_probability < original.goal_probability);
+        assert!(analysis.kicker_strategy[0].1 < original.kicker_strategy[0].1);
+
+        assert!(matches!(pk.with_modified_cell(3, 0, 0.5), Err(PayoffError::DimensionMismatch)));
+        assert!(matches!(pk.with_modified_cell(0, 0, 1.5), Err(PayoffError::InvalidProbability(_))));
+    }
+
     #[test]
     fn test_expected_goal_probability() {
         let pk = PenaltyKick::with_default_data();
@@ -233,4 +1553,13 @@ mod tests {
         let prob = pk.expected_goal_probability(&kick, &gk);
         assert_relative_eq!(prob, 0.58, epsilon = 0.001);
     }
+
+    #[test]
+    fn test_clamp_probabilities_accepts_verified_rounding() {
+        // -1e-7 passes GameSolution::verify, so clamping must not panic on it
+        let clamped = clamp_probabilities(&[-1e-7, 0.4, 0.6000001]);
+
+        assert_eq!(clamped[0], 0.0);
+        assert_relative_eq!(clamped.iter().sum::<f64>(), 1.0, epsilon = 1e-12);
+    }
 }
@@ -1,4 +1,5 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
 use std::path::Path;
 use thiserror::Error;
 
@@ -12,8 +13,11 @@ pub enum StatsError {
     InvalidData(String),
 }
 
+/// Success rate matrix indexed by `[kick_direction][gk_direction]`.
+pub type Matrix = Vec<Vec<f64>>;
+
 /// Raw PK statistics record from CSV.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PkRecord {
     pub kick_direction: String,
     pub gk_direction: String,
@@ -30,6 +34,146 @@ impl PkRecord {
             self.goals as f64 / self.attempts as f64
         }
     }
+
+    /// Calculates the success rate shrunk toward a prior rate.
+    ///
+    /// Implements `(goals + prior_rate * prior_strength) / (attempts + prior_strength)`,
+    /// where `prior_strength` acts as a number of pseudo-attempts at `prior_rate`.
+    pub fn smoothed_success_rate(&self, prior_rate: f64, prior_strength: f64) -> f64 {
+        let denominator = self.attempts as f64 + prior_strength;
+        if denominator <= 0.0 {
+            prior_rate
+        } else {
+            (self.goals as f64 + prior_rate * prior_strength) / denominator
+        }
+    }
+
+    /// Calculates the Wilson score interval for the success rate.
+    ///
+    /// Returns `(0.0, 1.0)` when there are no attempts, and an error unless
+    /// `confidence` lies in (0, 1).
+    pub fn success_interval(&self, confidence: f64) -> Result<(f64, f64), StatsError> {
+        wilson_interval(self.goals as u64, self.attempts as u64, confidence)
+    }
+}
+
+/// Weighted goals and attempts for one direction combination.
+///
+/// Counts are fractional because each record contributes in proportion to
+/// its weight.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedRate {
+    pub kick_direction: String,
+    pub gk_direction: String,
+    pub goals: f64,
+    pub attempts: f64,
+}
+
+impl WeightedRate {
+    /// Calculates the weighted success rate.
+    pub fn success_rate(&self) -> f64 {
+        if self.attempts <= 0.0 {
+            0.0
+        } else {
+            self.goals / self.attempts
+        }
+    }
+}
+
+/// Computes the Wilson score interval for `successes` out of `trials`.
+///
+/// Returns `(0.0, 1.0)` when `trials == 0`. Fails unless `confidence`
+/// lies strictly between 0 and 1.
+pub fn wilson_interval(successes: u64, trials: u64, confidence: f64) -> Result<(f64, f64), StatsError> {
+    let z = confidence_z(confidence)?;
+    Ok(wilson_bounds(successes, trials, z))
+}
+
+/// Fails unless the probability level `value`, described by `name`, lies in (0, 1).
+pub(crate) fn check_level(name: &str, value: f64) -> Result<(), StatsError> {
+    if value > 0.0 && value < 1.0 {
+        Ok(())
+    } else {
+        Err(StatsError::InvalidData(format!("{} must be in (0, 1), got {}", name, value)))
+    }
+}
+
+/// Two-sided critical value of the standard normal for `confidence`.
+fn confidence_z(confidence: f64) -> Result<f64, StatsError> {
+    check_level("Confidence level", confidence)?;
+    normal_quantile(0.5 + confidence / 2.0)
+}
+
+/// Wilson score interval for a precomputed critical value `z`.
+fn wilson_bounds(successes: u64, trials: u64, z: f64) -> (f64, f64) {
+    if trials == 0 {
+        return (0.0, 1.0);
+    }
+
+    let n = trials as f64;
+    let p = successes as f64 / n;
+    let z2 = z * z;
+
+    let denominator = 1.0 + z2 / n;
+    let center = (p + z2 / (2.0 * n)) / denominator;
+    let half_width = z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / denominator;
+
+    ((center - half_width).max(0.0), (center + half_width).min(1.0))
+}
+
+/// Inverse CDF of the standard normal distribution.
+///
+/// Uses Acklam's rational approximation (relative error below 1.2e-9).
+/// Fails unless `p` lies strictly between 0 and 1.
+pub(crate) fn normal_quantile(p: f64) -> Result<f64, StatsError> {
+    if !(p > 0.0 && p < 1.0) {
+        return Err(StatsError::InvalidData(format!(
+            "Probability must be in (0, 1), got {}",
+            p
+        )));
+    }
+    Ok(acklam_quantile(p))
+}
+
+/// Acklam's approximation, saturating to infinity outside (0, 1).
+fn acklam_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1, 2.209460984245205e2, -2.759285104469687e2,
+        1.38357751867269e2, -3.066479806614716e1, 2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1, 1.615858368580409e2, -1.556989798598866e2,
+        6.680131188771972e1, -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3, -3.223964580411365e-1, -2.400758277161838,
+        -2.549732539343734, 4.374664141464968, 2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3, 3.224671290700398e-1, 2.445134137142996,
+        3.754408661907416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    if p < P_LOW {
+        let q = (-2.0 * p.ln()).sqrt();
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    } else if p <= 1.0 - P_LOW {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    } else {
+        -acklam_quantile(1.0 - p)
+    }
 }
 
 /// Loads PK statistics from a CSV file.
@@ -51,24 +195,290 @@ pub fn load_pk_stats(path: impl AsRef<Path>) -> Result<Vec<PkRecord>, StatsError
     Ok(records)
 }
 
+/// Builds the 3x3 success rate matrix from a records CSV in a single pass.
+///
+/// Records are folded into per-cell goal and attempt totals as they are
+/// read, so memory use stays constant however large the file is. Repeated
+/// rows for the same cell are summed, as in [`records_to_matrix`].
+pub fn matrix_from_csv_streaming(path: impl AsRef<Path>) -> Result<Matrix, StatsError> {
+    let reader = csv::Reader::from_path(path)?;
+    let records = reader.into_deserialize().map(|result| result.map_err(StatsError::from));
+
+    let (matrix, filled) = collect_cells(records, PkRecord::success_rate)?;
+    check_filled(&filled)?;
+    Ok(matrix)
+}
+
+/// A single success rate cell as stored in a matrix CSV.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct RateRecord {
+    kick_direction: String,
+    gk_direction: String,
+    rate: f64,
+}
+
+/// Writes PK records to a CSV file in the format read by [`load_pk_stats`].
+pub fn write_records_csv(path: impl AsRef<Path>, records: &[PkRecord]) -> Result<(), StatsError> {
+    let mut writer = csv::Writer::from_path(path)?;
+
+    for record in records {
+        writer.serialize(record)?;
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Writes a 3x3 success rate matrix to a CSV file.
+///
+/// Output CSV format:
+/// kick_direction,gk_direction,rate
+/// left,left,0.58
+/// left,center,0.93
+/// ...
+pub fn write_matrix_csv(path: impl AsRef<Path>, matrix: &[Vec<f64>]) -> Result<(), StatsError> {
+    if matrix.len() != 3 || matrix.iter().any(|row| row.len() != 3) {
+        return Err(StatsError::InvalidData("Matrix must be 3x3".into()));
+    }
+
+    let mut writer = csv::Writer::from_path(path)?;
+
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, &rate) in row.iter().enumerate() {
+            writer.serialize(RateRecord {
+                kick_direction: index_to_direction(i).into(),
+                gk_direction: index_to_direction(j).into(),
+                rate,
+            })?;
+        }
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Loads a 3x3 success rate matrix written by [`write_matrix_csv`].
+pub fn load_matrix_csv(path: impl AsRef<Path>) -> Result<Matrix, StatsError> {
+    let mut reader = csv::Reader::from_path(path)?;
+    let mut matrix = vec![vec![0.0; 3]; 3];
+    let mut filled = vec![vec![false; 3]; 3];
+
+    for result in reader.deserialize() {
+        let record: RateRecord = result?;
+        let kick_idx = direction_to_index(&record.kick_direction)?;
+        let gk_idx = direction_to_index(&record.gk_direction)?;
+
+        matrix[kick_idx][gk_idx] = record.rate;
+        filled[kick_idx][gk_idx] = true;
+    }
+
+    check_filled(&filled)?;
+    Ok(matrix)
+}
+
 /// Converts PK records into a 3x3 success rate matrix.
 ///
 /// Matrix layout:
 /// - Rows: Kick direction (left=0, center=1, right=2)
 /// - Columns: GK direction (left=0, center=1, right=2)
+///
+/// Repeated rows for the same cell are summed, as in [`aggregate_records`],
+/// and every other records loader in this module follows the same rule.
+///
+/// Records with more goals than attempts are rejected, here and in the
+/// other loaders; use [`records_to_matrix_lenient`] to clamp them instead.
 pub fn records_to_matrix(records: &[PkRecord]) -> Result<Vec<Vec<f64>>, StatsError> {
-    let mut matrix = vec![vec![0.0; 3]; 3];
-    let mut filled = vec![vec![false; 3]; 3];
+    fill_matrix(records, PkRecord::success_rate)
+}
 
-    for record in records {
+/// How `records_to_matrix_imputed` fills cells without records.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImputeStrategy {
+    /// Mean of the observed cells for the same kick direction
+    RowMean,
+    /// Mean of the observed cells for the same dive direction
+    ColMean,
+    /// Mean of all observed cells
+    GlobalMean,
+    /// A fixed success rate
+    Fixed(f64),
+}
+
+/// Converts PK records into a 3x3 success rate matrix, imputing missing cells.
+///
+/// Observed cells are computed as in [`records_to_matrix`]; the rest are
+/// filled by `strategy` from the observed rates only. Returns the matrix and
+/// the `(kick, gk)` positions that were imputed. Fails if a mean has no
+/// observed cells to average or a fixed rate is outside [0, 1].
+pub fn records_to_matrix_imputed(
+    records: &[PkRecord],
+    strategy: ImputeStrategy,
+) -> Result<(Matrix, BTreeSet<(usize, usize)>), StatsError> {
+    if let ImputeStrategy::Fixed(rate) = strategy
+        && !(0.0..=1.0).contains(&rate)
+    {
+        return Err(StatsError::InvalidData(format!("Imputed rate out of range: {}", rate)));
+    }
+
+    let (observed, filled) = collect_cells(records.iter().cloned().map(Ok), PkRecord::success_rate)?;
+    let missing: BTreeSet<(usize, usize)> = (0..3)
+        .flat_map(|i| (0..3).map(move |j| (i, j)))
+        .filter(|&(i, j)| !filled[i][j])
+        .collect();
+
+    // Mean of the observed rates among `cells`
+    let mean = |cells: Vec<(usize, usize)>, what: String| {
+        let rates: Vec<f64> = cells.into_iter().filter(|&(i, j)| filled[i][j]).map(|(i, j)| observed[i][j]).collect();
+        if rates.is_empty() {
+            return Err(StatsError::InvalidData(format!("No observed data for {} to impute from", what)));
+        }
+        Ok(rates.iter().sum::<f64>() / rates.len() as f64)
+    };
+
+    let mut matrix = observed.clone();
+    for &(i, j) in &missing {
+        matrix[i][j] = match strategy {
+            ImputeStrategy::RowMean => {
+                mean((0..3).map(|k| (i, k)).collect(), format!("kick={}", index_to_direction(i)))?
+            }
+            ImputeStrategy::ColMean => {
+                mean((0..3).map(|k| (k, j)).collect(), format!("gk={}", index_to_direction(j)))?
+            }
+            ImputeStrategy::GlobalMean => {
+                mean((0..3).flat_map(|k| (0..3).map(move |l| (k, l))).collect(), "any cell".to_string())?
+            }
+            ImputeStrategy::Fixed(rate) => rate,
+        };
+    }
+
+    Ok((matrix, missing))
+}
+
+/// Like [`records_to_matrix`], but clamps rates above 1 instead of erroring.
+///
+/// Each record's goals are capped at its attempts before records are summed.
+pub fn records_to_matrix_lenient(records: &[PkRecord]) -> Result<Vec<Vec<f64>>, StatsError> {
+    let clamped: Vec<PkRecord> = records
+        .iter()
+        .map(|record| PkRecord { goals: record.goals.min(record.attempts), ..record.clone() })
+        .collect();
+    fill_matrix(&clamped, PkRecord::success_rate)
+}
+
+/// Converts PK records into a 3x3 success rate matrix with Bayesian smoothing.
+///
+/// Each cell is pulled toward `prior_rate` (e.g. a league average) with a
+/// weight of `prior_strength` pseudo-attempts, which stabilizes cells backed
+/// by only a handful of kicks.
+pub fn records_to_matrix_smoothed(
+    records: &[PkRecord],
+    prior_rate: f64,
+    prior_strength: f64,
+) -> Result<Vec<Vec<f64>>, StatsError> {
+    if !(0.0..=1.0).contains(&prior_rate) {
+        return Err(StatsError::InvalidData(format!(
+            "Prior rate out of range: {}",
+            prior_rate
+        )));
+    }
+    if prior_strength < 0.0 {
+        return Err(StatsError::InvalidData(format!(
+            "Negative prior strength: {}",
+            prior_strength
+        )));
+    }
+
+    fill_matrix(records, |record| {
+        record.smoothed_success_rate(prior_rate, prior_strength)
+    })
+}
+
+/// Converts PK records into a success rate matrix plus Wilson interval widths.
+///
+/// The second matrix holds `upper - lower` for each cell at the given
+/// confidence level, so wider values mark less trustworthy rates.
+pub fn records_to_matrix_with_intervals(
+    records: &[PkRecord],
+    confidence: f64,
+) -> Result<(Matrix, Matrix), StatsError> {
+    let z = confidence_z(confidence)?;
+    let rates = fill_matrix(records, PkRecord::success_rate)?;
+    let widths = fill_matrix(records, |record| {
+        let (lower, upper) = wilson_bounds(record.goals as u64, record.attempts as u64, z);
+        upper - lower
+    })?;
+
+    Ok((rates, widths))
+}
+
+/// Rejects a record with more goals than attempts.
+fn check_goals(index: usize, record: &PkRecord) -> Result<(), StatsError> {
+    if record.goals > record.attempts {
+        return Err(StatsError::InvalidData(format!(
+            "Record {} (kick={}, gk={}) has {} goals from {} attempts",
+            index, record.kick_direction, record.gk_direction, record.goals, record.attempts
+        )));
+    }
+
+    Ok(())
+}
+
+/// Fills a 3x3 matrix using the given per-record rate function.
+fn fill_matrix<F>(records: &[PkRecord], rate: F) -> Result<Vec<Vec<f64>>, StatsError>
+where
+    F: Fn(&PkRecord) -> f64,
+{
+    let (matrix, filled) = collect_cells(records.iter().cloned().map(Ok), rate)?;
+    check_filled(&filled)?;
+    Ok(matrix)
+}
+
+/// Sums records into per-cell totals and places each cell's rate in a 3x3
+/// matrix, marking which cells got a record.
+///
+/// Repeated rows for the same cell add their goals and attempts before
+/// `rate` is applied to the total. Records with more goals than attempts
+/// are rejected, so every loader built on this enforces the check.
+fn collect_cells<I, F>(records: I, rate: F) -> Result<(Matrix, Vec<Vec<bool>>), StatsError>
+where
+    I: IntoIterator<Item = Result<PkRecord, StatsError>>,
+    F: Fn(&PkRecord) -> f64,
+{
+    let mut totals: Vec<Vec<Option<PkRecord>>> = vec![vec![None; 3]; 3];
+
+    for (index, record) in records.into_iter().enumerate() {
+        let record = record?;
+        check_goals(index, &record)?;
         let kick_idx = direction_to_index(&record.kick_direction)?;
         let gk_idx = direction_to_index(&record.gk_direction)?;
 
-        matrix[kick_idx][gk_idx] = record.success_rate();
-        filled[kick_idx][gk_idx] = true;
+        match &mut totals[kick_idx][gk_idx] {
+            Some(total) => {
+                let overflow = || {
+                    StatsError::InvalidData(format!(
+                        "Too many attempts for kick={}, gk={}",
+                        index_to_direction(kick_idx),
+                        index_to_direction(gk_idx)
+                    ))
+                };
+                total.goals = total.goals.checked_add(record.goals).ok_or_else(overflow)?;
+                total.attempts = total.attempts.checked_add(record.attempts).ok_or_else(overflow)?;
+            }
+            cell => *cell = Some(record),
+        }
     }
 
-    // Check all cells are filled
+    let matrix = totals
+        .iter()
+        .map(|row| row.iter().map(|cell| cell.as_ref().map_or(0.0, &rate)).collect())
+        .collect();
+    let filled = totals.iter().map(|row| row.iter().map(Option::is_some).collect()).collect();
+
+    Ok((matrix, filled))
+}
+
+/// Checks that every cell of the 3x3 matrix received data.
+fn check_filled(filled: &[Vec<bool>]) -> Result<(), StatsError> {
     for (i, row) in filled.iter().enumerate() {
         for (j, &is_filled) in row.iter().enumerate() {
             if !is_filled {
@@ -81,11 +491,11 @@ pub fn records_to_matrix(records: &[PkRecord]) -> Result<Vec<Vec<f64>>, StatsErr
         }
     }
 
-    Ok(matrix)
+    Ok(())
 }
 
 /// Converts direction string to matrix index.
-fn direction_to_index(direction: &str) -> Result<usize, StatsError> {
+pub(crate) fn direction_to_index(direction: &str) -> Result<usize, StatsError> {
     match direction.to_lowercase().as_str() {
         "left" | "l" => Ok(0),
         "center" | "centre" | "middle" | "c" | "m" => Ok(1),
@@ -134,9 +544,42 @@ pub fn aggregate_records(records: Vec<PkRecord>) -> Vec<PkRecord> {
         .collect()
 }
 
+/// Aggregates records like `aggregate_records`, scaling each by its weight.
+///
+/// Effective goals and attempts are `sum(weight * goals)` and
+/// `sum(weight * attempts)`, so e.g. exponentially decaying weights favour
+/// recent matches. Negative weights are treated as zero.
+pub fn aggregate_records_weighted(records: Vec<(PkRecord, f64)>) -> Vec<WeightedRate> {
+    use std::collections::HashMap;
+
+    let mut aggregated: HashMap<(String, String), (f64, f64)> = HashMap::new();
+
+    for (record, weight) in records {
+        let weight = weight.max(0.0);
+        let key = (
+            record.kick_direction.to_lowercase(),
+            record.gk_direction.to_lowercase(),
+        );
+        let entry = aggregated.entry(key).or_insert((0.0, 0.0));
+        entry.0 += weight * record.goals as f64;
+        entry.1 += weight * record.attempts as f64;
+    }
+
+    aggregated
+        .into_iter()
+        .map(|((kick, gk), (goals, attempts))| WeightedRate {
+            kick_direction: kick,
+            gk_direction: gk,
+            goals,
+            attempts,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::{pk_record, pk_records};
 
     #[test]
     fn test_direction_parsing() {
@@ -148,13 +591,169 @@ mod tests {
 
     #[test]
     fn test_success_rate() {
-        let record = PkRecord {
-            kick_direction: "left".into(),
-            gk_direction: "left".into(),
-            goals: 58,
-            attempts: 100,
-        };
+        let record = pk_record("left", "left", 58, 100);
 
         assert!((record.success_rate() - 0.58).abs() < 0.001);
     }
+
+    #[test]
+    fn test_wilson_interval() {
+        let record = pk_record("left", "left", 58, 100);
+
+        let (lower, upper) = record.success_interval(0.95).unwrap();
+        assert!((lower - 0.4821).abs() < 0.0005);
+        assert!((upper - 0.6720).abs() < 0.0005);
+
+        let empty = PkRecord { goals: 0, attempts: 0, ..record.clone() };
+        assert_eq!(empty.success_interval(0.95).unwrap(), (0.0, 1.0));
+
+        for confidence in [0.0, 1.0, 1.5, -0.2, f64::NAN] {
+            assert!(record.success_interval(confidence).is_err(), "{}", confidence);
+            assert!(empty.success_interval(confidence).is_err(), "{}", confidence);
+        }
+        assert!(records_to_matrix_with_intervals(&pk_records([[5; 3]; 3], 10), 1.0).is_err());
+        assert!(normal_quantile(0.0).is_err());
+        assert!((normal_quantile(0.975).unwrap() - 1.959964).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_matrix_with_intervals() {
+        let (rates, widths) = records_to_matrix_with_intervals(&pk_records([[58; 3]; 3], 100), 0.95).unwrap();
+        assert!((rates[1][1] - 0.58).abs() < 1e-9);
+        assert!((widths[1][1] - (0.6720 - 0.4821)).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_matrix_csv_round_trip() {
+        let matrix = vec![
+            vec![0.58, 0.93, 0.95],
+            vec![0.83, 0.44, 0.83],
+            vec![0.93, 0.90, 0.60],
+        ];
+        let path = std::env::temp_dir().join(format!("fgt_matrix_{}.csv", std::process::id()));
+
+        write_matrix_csv(&path, &matrix).unwrap();
+        let loaded = load_matrix_csv(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, matrix);
+    }
+
+    #[test]
+    fn test_goals_exceeding_attempts() {
+        let mut records = pk_records([[50; 3]; 3], 100);
+        records[4].goals = 110;
+
+        match records_to_matrix(&records) {
+            Err(StatsError::InvalidData(message)) => {
+                assert!(message.contains("Record 4"), "{}", message);
+                assert!(message.contains("110 goals from 100 attempts"), "{}", message);
+            }
+            other => panic!("expected InvalidData, got {:?}", other),
+        }
+
+        let clamped = records_to_matrix_lenient(&records).unwrap();
+        assert_eq!(clamped[1][1], 1.0);
+        assert_eq!(clamped[0][0], 0.5);
+
+        // The smoothed, interval and streaming loaders share the check
+        assert!(records_to_matrix_smoothed(&records, 0.75, 10.0).is_err());
+        assert!(records_to_matrix_with_intervals(&records, 0.95).is_err());
+        let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/too_many_goals.csv");
+        assert!(matches!(matrix_from_csv_streaming(fixture), Err(StatsError::InvalidData(_))));
+    }
+
+    #[test]
+    fn test_imputed_matrix() {
+        // Keeper never faced a center kick while diving center
+        let mut records = pk_records([[50; 3]; 3], 100);
+        records[0].goals = 80;
+        records[1].goals = 60;
+        records[7].goals = 90;
+        records.remove(4);
+
+        let impute = |strategy| records_to_matrix_imputed(&records, strategy).unwrap();
+
+        let (matrix, imputed) = impute(ImputeStrategy::RowMean);
+        assert_eq!(imputed, BTreeSet::from([(1, 1)]));
+        assert!((matrix[1][1] - 0.5).abs() < 1e-12);
+        assert!((matrix[0][0] - 0.8).abs() < 1e-12);
+
+        // Column: left-center 0.6 and right-center 0.9
+        let (matrix, _) = impute(ImputeStrategy::ColMean);
+        assert!((matrix[1][1] - 0.75).abs() < 1e-12);
+
+        // Eight observed cells: five at 0.5 plus 0.8, 0.6 and 0.9
+        let (matrix, _) = impute(ImputeStrategy::GlobalMean);
+        assert!((matrix[1][1] - 4.8 / 8.0).abs() < 1e-12);
+
+        let (matrix, _) = impute(ImputeStrategy::Fixed(0.3));
+        assert_eq!(matrix[1][1], 0.3);
+
+        assert!(records_to_matrix_imputed(&records, ImputeStrategy::Fixed(1.2)).is_err());
+        assert!(records_to_matrix(&records).is_err());
+    }
+
+    #[test]
+    fn test_streaming_matrix_matches_loaded_records() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/data/pk_stats.csv");
+
+        let streamed = matrix_from_csv_streaming(path).unwrap();
+        let loaded = records_to_matrix(&load_pk_stats(path).unwrap()).unwrap();
+
+        assert_eq!(streamed, loaded);
+    }
+
+    #[test]
+    fn test_duplicate_records_are_summed() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/duplicates.csv");
+        let records = load_pk_stats(path).unwrap();
+
+        let streamed = matrix_from_csv_streaming(path).unwrap();
+        let loaded = records_to_matrix(&records).unwrap();
+        let (imputed, _) = records_to_matrix_imputed(&records, ImputeStrategy::GlobalMean).unwrap();
+
+        // left/left: (58 + 2) / (100 + 20); center/center: (44 + 16) / (100 + 20)
+        assert!((loaded[0][0] - 0.5).abs() < 1e-12);
+        assert!((loaded[1][1] - 0.5).abs() < 1e-12);
+        assert_eq!(streamed, loaded);
+        assert_eq!(imputed, loaded);
+    }
+
+    #[test]
+    fn test_records_csv_round_trip() {
+        let records = pk_records([[58; 3]; 3], 100);
+        let path = std::env::temp_dir().join(format!("fgt_records_{}.csv", std::process::id()));
+
+        write_records_csv(&path, &records).unwrap();
+        let loaded = load_pk_stats(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(records_to_matrix(&loaded).unwrap(), records_to_matrix(&records).unwrap());
+    }
+
+    #[test]
+    fn test_smoothing_shrinks_sparse_cells() {
+        let sparse = records_to_matrix_smoothed(&pk_records([[1; 3]; 3], 1), 0.75, 10.0).unwrap();
+        // (1 + 7.5) / (1 + 10) = 0.7727...
+        assert!((sparse[0][0] - 8.5 / 11.0).abs() < 1e-9);
+        assert!(sparse[0][0] < 0.8);
+
+        let dense = records_to_matrix_smoothed(&pk_records([[900; 3]; 3], 1000), 0.75, 10.0).unwrap();
+        assert!((dense[0][0] - 0.9).abs() < 0.002);
+    }
+
+    #[test]
+    fn test_weighted_aggregation_favours_heavier_record() {
+        let record = |goals| pk_record("Left", "right", goals, 100);
+        let rate = |recent_weight| {
+            let rates = aggregate_records_weighted(vec![(record(60), 1.0), (record(90), recent_weight)]);
+            assert_eq!(rates.len(), 1);
+            assert_eq!(rates[0].kick_direction, "left");
+            rates[0].success_rate()
+        };
+
+        assert!((rate(1.0) - 0.75).abs() < 1e-12);
+        assert!((rate(2.0) - 0.8).abs() < 1e-12);
+    }
 }
@@ -2,6 +2,8 @@ pub mod solver;
 pub mod football;
 pub mod analysis;
 pub mod visualization;
+#[cfg(any(test, feature = "testing"))]
+pub mod test_util;
 
 pub use solver::simplex::Simplex;
 pub use solver::game::GameSolver;
@@ -1,19 +1,130 @@
-use football_game_theory::football::penalty::PenaltyKick;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+use football_game_theory::football::penalty::{AnalysisError, PenaltyAnalysis, PenaltyKick};
+use football_game_theory::football::stats::load_pk_stats;
 use football_game_theory::analysis::simulation::Simulator;
 use football_game_theory::analysis::sensitivity::SensitivityAnalyzer;
 use football_game_theory::visualization::ascii::GoalVisualizer;
 use football_game_theory::visualization::heatmap::HeatmapRenderer;
 use football_game_theory::visualization::chart::BarChart;
 
-fn main() {
+/// Nash equilibrium strategy finder for penalty kicks.
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the full walkthrough on the built-in data (default)
+    Demo,
+    /// Solves the equilibrium for kick records in a CSV file
+    Analyze {
+        /// CSV with kick_direction,gk_direction,goals,attempts columns
+        #[arg(long)]
+        csv: PathBuf,
+    },
+    /// Plays equilibrium and uniform strategies against each other
+    Simulate {
+        /// Number of kicks per run
+        #[arg(long, default_value_t = 10000)]
+        kicks: u32,
+        /// Random seed
+        #[arg(long, default_value_t = 42)]
+        seed: u64,
+        /// Kick records to use instead of the built-in data
+        #[arg(long)]
+        csv: Option<PathBuf>,
+    },
+    /// Ranks success rates by how much changing them moves the equilibrium
+    Sensitivity {
+        /// Amount added to each success rate
+        #[arg(long, default_value_t = 0.05, value_parser = parse_delta)]
+        delta: f64,
+        /// Kick records to use instead of the built-in data
+        #[arg(long)]
+        csv: Option<PathBuf>,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command.unwrap_or(Command::Demo) {
+        Command::Demo => demo(),
+        Command::Analyze { csv } => load_pk(Some(csv)).and_then(|pk| {
+            print_banner();
+            print_analysis(&pk).map(|_| ())
+        }),
+        Command::Simulate { kicks, seed, csv } => load_pk(csv).and_then(|pk| {
+            let analysis = pk.analyze()?;
+            print_simulation(&pk, &analysis, kicks, seed);
+            Ok(())
+        }),
+        Command::Sensitivity { delta, csv } => {
+            load_pk(csv).and_then(|pk| print_sensitivity(&pk, delta))
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses a sensitivity step, which must be a positive finite number.
+fn parse_delta(s: &str) -> Result<f64, String> {
+    let delta: f64 = s.parse().map_err(|e| format!("{}", e))?;
+    if delta.is_finite() && delta > 0.0 {
+        Ok(delta)
+    } else {
+        Err(format!("delta must be a positive finite number, got {}", s))
+    }
+}
+
+/// Builds the PK model from a records CSV, or the built-in data if none is given.
+fn load_pk(csv: Option<PathBuf>) -> Result<PenaltyKick, AnalysisError> {
+    match csv {
+        Some(path) => PenaltyKick::from_records(&load_pk_stats(path)?),
+        None => Ok(PenaltyKick::with_default_data()),
+    }
+}
+
+fn demo() -> Result<(), AnalysisError> {
+    print_banner();
+
+    // Analyze PK with default real-world data
+    let pk = PenaltyKick::with_default_data();
+
+    let analysis = print_analysis(&pk)?;
+    print_simulation(&pk, &analysis, 10000, 42);
+
+    print_sensitivity(&pk, 0.05)?;
+
+    println!("═══════════════════════════════════════════════════════════════");
+    println!("                         COMPLETE                               ");
+    println!("═══════════════════════════════════════════════════════════════");
+
+    Ok(())
+}
+
+fn print_banner() {
     println!("╔════════════════════════════════════════════════════════════╗");
     println!("║       FOOTBALL GAME THEORY: PK ANALYSIS                    ║");
     println!("║       Nash Equilibrium Strategy Finder                     ║");
     println!("╚════════════════════════════════════════════════════════════╝\n");
+}
 
-    // Analyze PK with default real-world data
-    let pk = PenaltyKick::with_default_data();
-
+/// Prints the payoff heatmap, equilibrium strategies and summary.
+fn print_analysis(pk: &PenaltyKick) -> Result<PenaltyAnalysis, AnalysisError> {
     // Visualize payoff matrix as heatmap
     let heatmap = HeatmapRenderer::new();
     let matrix = pk.payoff_matrix().matrix();
@@ -22,128 +133,122 @@ fn main() {
 
     println!("{}", heatmap.render(matrix, &rows, &cols, "PAYOFF MATRIX (Goal Success Rates)"));
 
-    match pk.analyze() {
-        Ok(analysis) => {
-            // Extract strategy values
-            let kicker_strat: Vec<f64> = analysis.kicker_strategy.iter().map(|(_, p)| *p).collect();
-            let gk_strat: Vec<f64> = analysis.goalkeeper_strategy.iter().map(|(_, p)| *p).collect();
-
-            // Visualize strategies with goal diagram
-            let goal_viz = GoalVisualizer::new();
-            println!("{}", goal_viz.render_kicker_strategy(
-                kicker_strat[0], kicker_strat[1], kicker_strat[2]
-            ));
-            println!("{}", goal_viz.render_goalkeeper_strategy(
-                gk_strat[0], gk_strat[1], gk_strat[2]
-            ));
-
-            // Bar chart for strategies
-            let chart = BarChart::new();
-            let kicker_data: Vec<(&str, f64)> = vec![
-                ("Left", kicker_strat[0]),
-                ("Center", kicker_strat[1]),
-                ("Right", kicker_strat[2]),
-            ];
-            println!("{}", chart.render("KICKER OPTIMAL STRATEGY", &kicker_data, 1.0));
-
-            let gk_data: Vec<(&str, f64)> = vec![
-                ("Left", gk_strat[0]),
-                ("Center", gk_strat[1]),
-                ("Right", gk_strat[2]),
-            ];
-            println!("{}", chart.render("GOALKEEPER OPTIMAL STRATEGY", &gk_data, 1.0));
-
-            // Summary statistics
-            println!("╔════════════════════════════════════════════════════════════╗");
-            println!("║                    NASH EQUILIBRIUM                        ║");
-            println!("╠════════════════════════════════════════════════════════════╣");
-            println!("║  Kicker:     {}",
-                format!("{:<49}║", analysis.kicker_strategy_string()));
-            println!("║  Goalkeeper: {}",
-                format!("{:<49}║", analysis.goalkeeper_strategy_string()));
-            println!("║  Game Value: {}",
-                format!("{:<49}║", format!("{:.1}% expected goal rate", analysis.goal_probability * 100.0)));
-            println!("╚════════════════════════════════════════════════════════════╝\n");
-
-            // Run simulation
-            println!("═══════════════════════════════════════════════════════════════");
-            println!("                    MONTE CARLO SIMULATION                      ");
-            println!("═══════════════════════════════════════════════════════════════\n");
-
-            let sim = Simulator::new().seed(42);
-            let result = sim.simulate(&kicker_strat, &gk_strat, 10000);
-
-            println!("  Simulated {} penalty kicks with optimal strategies", result.total_kicks);
-            println!("  Results: {} goals ({:.1}%)\n",
-                result.goals_scored,
-                result.goal_percentage()
-            );
-
-            // Compare with uniform strategy
-            let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
-            let uniform_result = sim.simulate(&uniform, &uniform, 10000);
-
-            // Comparison chart
-            println!("{}", chart.render_comparison(
-                "STRATEGY COMPARISON: Optimal vs Uniform",
-                &["Left", "Center", "Right"],
-                ("Optimal", &kicker_strat),
-                ("Uniform", &uniform),
-            ));
-
-            println!("  Optimal strategy result: {:.1}% goals", result.goal_percentage());
-            println!("  Uniform strategy result: {:.1}% goals", uniform_result.goal_percentage());
-            println!("  Difference: {:+.1}%\n",
-                result.goal_percentage() - uniform_result.goal_percentage());
-        }
-        Err(e) => {
-            eprintln!("Analysis failed: {}", e);
-        }
-    }
+    let analysis = pk.analyze()?;
+
+    // Extract strategy values
+    let kicker_strat: Vec<f64> = analysis.kicker_strategy.iter().map(|(_, p)| *p).collect();
+    let gk_strat: Vec<f64> = analysis.goalkeeper_strategy.iter().map(|(_, p)| *p).collect();
+
+    // Visualize strategies with goal diagram
+    let goal_viz = GoalVisualizer::new();
+    println!("{}", goal_viz.render_kicker_strategy(
+        kicker_strat[0], kicker_strat[1], kicker_strat[2]
+    ));
+    println!("{}", goal_viz.render_goalkeeper_strategy(
+        gk_strat[0], gk_strat[1], gk_strat[2]
+    ));
+
+    // Bar chart for strategies
+    let chart = BarChart::new();
+    let kicker_data: Vec<(&str, f64)> = vec![
+        ("Left", kicker_strat[0]),
+        ("Center", kicker_strat[1]),
+        ("Right", kicker_strat[2]),
+    ];
+    println!("{}", chart.render("KICKER OPTIMAL STRATEGY", &kicker_data, 1.0));
+
+    let gk_data: Vec<(&str, f64)> = vec![
+        ("Left", gk_strat[0]),
+        ("Center", gk_strat[1]),
+        ("Right", gk_strat[2]),
+    ];
+    println!("{}", chart.render("GOALKEEPER OPTIMAL STRATEGY", &gk_data, 1.0));
+
+    // Summary statistics
+    println!("{}\n", analysis);
+
+    Ok(analysis)
+}
+
+/// Simulates the equilibrium against uniform play and compares the results.
+fn print_simulation(pk: &PenaltyKick, analysis: &PenaltyAnalysis, kicks: u32, seed: u64) {
+    let kicker_strat: Vec<f64> = analysis.kicker_strategy.iter().map(|(_, p)| *p).collect();
+    let gk_strat: Vec<f64> = analysis.goalkeeper_strategy.iter().map(|(_, p)| *p).collect();
 
-    // Sensitivity analysis
     println!("═══════════════════════════════════════════════════════════════");
-    println!("                    SENSITIVITY ANALYSIS                        ");
+    println!("                    MONTE CARLO SIMULATION                      ");
     println!("═══════════════════════════════════════════════════════════════\n");
 
-    let analyzer = SensitivityAnalyzer::with_default_data();
-    match analyzer.find_critical_parameters(0.05) {
-        Ok(critical) => {
-            println!("  Most sensitive parameters (when changed by +5%):\n");
-
-            let chart = BarChart::new();
-            let sensitivity_data: Vec<(&str, f64)> = critical
-                .iter()
-                .take(5)
-                .map(|(row, col, sens)| {
-                    let label = match (*row, *col) {
-                        (0, 0) => "L vs L",
-                        (0, 1) => "L vs C",
-                        (0, 2) => "L vs R",
-                        (1, 0) => "C vs L",
-                        (1, 1) => "C vs C",
-                        (1, 2) => "C vs R",
-                        (2, 0) => "R vs L",
-                        (2, 1) => "R vs C",
-                        (2, 2) => "R vs R",
-                        _ => "?",
-                    };
-                    (label, *sens)
-                })
-                .collect();
-
-            let max_sens = sensitivity_data.iter().map(|(_, v)| *v).fold(0.0, f64::max);
-            println!("{}", chart.render("PARAMETER SENSITIVITY (Kick vs GK)", &sensitivity_data, max_sens * 1.2));
-
-            println!("  Key insight: Changes to diagonal elements (same direction)");
-            println!("  have the highest impact on optimal strategies.\n");
-        }
+    let mut sim = match Simulator::with_matrix(pk.payoff_matrix().matrix().clone()) {
+        Ok(sim) => sim.seed(seed),
         Err(e) => {
-            eprintln!("  Sensitivity analysis failed: {}", e);
+            eprintln!("Simulation failed: {}", e);
+            return;
         }
-    }
+    };
+    let result = sim.simulate(&kicker_strat, &gk_strat, kicks);
+
+    println!("  Simulated {} penalty kicks with optimal strategies", result.total_kicks);
+    println!("  Results: {} goals ({:.1}%)\n",
+        result.goals_scored,
+        result.goal_percentage()
+    );
+
+    // Compare with uniform strategy
+    let uniform = vec![1.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0];
+    let uniform_result = sim.simulate(&uniform, &uniform, kicks);
+
+    // Comparison chart
+    let chart = BarChart::new();
+    println!("{}", chart.render_comparison(
+        "STRATEGY COMPARISON: Optimal vs Uniform",
+        &["Left", "Center", "Right"],
+        ("Optimal", &kicker_strat),
+        ("Uniform", &uniform),
+    ));
+
+    println!("  Optimal strategy result: {:.1}% goals", result.goal_percentage());
+    println!("  Uniform strategy result: {:.1}% goals", uniform_result.goal_percentage());
+    println!("  Difference: {:+.1}%\n",
+        result.goal_percentage() - uniform_result.goal_percentage());
+}
 
+/// Charts the success rates the equilibrium is most sensitive to.
+fn print_sensitivity(pk: &PenaltyKick, delta: f64) -> Result<(), AnalysisError> {
     println!("═══════════════════════════════════════════════════════════════");
-    println!("                         COMPLETE                               ");
-    println!("═══════════════════════════════════════════════════════════════");
+    println!("                    SENSITIVITY ANALYSIS                        ");
+    println!("═══════════════════════════════════════════════════════════════\n");
+
+    let analyzer = SensitivityAnalyzer::new(pk.payoff_matrix().matrix().clone());
+    let critical = analyzer.find_critical_parameters(delta)?;
+    println!("  Most sensitive parameters (when changed by {:+.0}%):\n", delta * 100.0);
+
+    let chart = BarChart::new();
+    let sensitivity_data: Vec<(&str, f64)> = critical
+        .iter()
+        .take(5)
+        .map(|(row, col, sens)| {
+            let label = match (*row, *col) {
+                (0, 0) => "L vs L",
+                (0, 1) => "L vs C",
+                (0, 2) => "L vs R",
+                (1, 0) => "C vs L",
+                (1, 1) => "C vs C",
+                (1, 2) => "C vs R",
+                (2, 0) => "R vs L",
+                (2, 1) => "R vs C",
+                (2, 2) => "R vs R",
+                _ => "?",
+            };
+            (label, *sens)
+        })
+        .collect();
+
+    let max_sens = sensitivity_data.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+    println!("{}", chart.render("PARAMETER SENSITIVITY (Kick vs GK)", &sensitivity_data, max_sens * 1.2));
+
+    println!("  Key insight: Changes to diagonal elements (same direction)");
+    println!("  have the highest impact on optimal strategies.\n");
+
+    Ok(())
 }
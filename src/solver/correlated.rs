@@ -0,0 +1,152 @@
+use super::simplex::{Simplex, SimplexError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CorrelatedError {
+    #[error("Empty payoff matrix")]
+    EmptyMatrix,
+    #[error("Payoff matrices must be rectangular and the same shape")]
+    DimensionMismatch,
+    #[error("Solver error: {0}")]
+    SolverError(#[from] SimplexError),
+}
+
+/// Finds a correlated equilibrium of a bimatrix game maximizing total welfare.
+///
+/// `a` holds Row's payoffs and `b` Column's, both indexed `[row][col]`.
+/// A mediator draws a joint action `(i, j)` from the returned distribution
+/// and privately recommends `i` to Row and `j` to Column. The incentive
+/// constraints make obeying optimal for both players:
+///
+/// - for every recommended row `i` and deviation `k`:
+///   `sum_j x_ij * (a_kj - a_ij) <= 0`
+/// - for every recommended column `j` and deviation `l`:
+///   `sum_i x_ij * (b_il - b_ij) <= 0`
+///
+/// Among such distributions the one maximizing `sum x_ij * (a_ij + b_ij)`
+/// is found with Simplex. The result is a joint probability matrix shaped
+/// like `a`.
+pub fn correlated_equilibrium(a: &[Vec<f64>], b: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, CorrelatedError> {
+    if a.is_empty() || a[0].is_empty() {
+        return Err(CorrelatedError::EmptyMatrix);
+    }
+
+    let num_rows = a.len();
+    let num_cols = a[0].len();
+
+    if b.len() != num_rows || a.iter().chain(b).any(|row| row.len() != num_cols) {
+        return Err(CorrelatedError::DimensionMismatch);
+    }
+
+    // Variable x_ij lives at index i * num_cols + j
+    let var = |i: usize, j: usize| i * num_cols + j;
+    let num_vars = num_rows * num_cols;
+
+    let mut constraints = Vec::new();
+
+    for i in 0..num_rows {
+        for k in (0..num_rows).filter(|&k| k != i) {
+            let mut row = vec![0.0; num_vars];
+            for j in 0..num_cols {
+                row[var(i, j)] = a[k][j] - a[i][j];
+            }
+            constraints.push(row);
+        }
+    }
+
+    for j in 0..num_cols {
+        for l in (0..num_cols).filter(|&l| l != j) {
+            let mut row = vec![0.0; num_vars];
+            for i in 0..num_rows {
+                row[var(i, j)] = b[i][l] - b[i][j];
+            }
+            constraints.push(row);
+        }
+    }
+
+    let mut rhs = vec![0.0; constraints.len()];
+
+    // Total mass at most 1; the incentive constraints are homogeneous, so
+    // with strictly positive objective weights the optimum uses all of it
+    constraints.push(vec![1.0; num_vars]);
+    rhs.push(1.0);
+
+    let welfare: Vec<f64> = (0..num_vars)
+        .map(|v| a[v / num_cols][v % num_cols] + b[v / num_cols][v % num_cols])
+        .collect();
+    let min_welfare = welfare.iter().cloned().fold(f64::INFINITY, f64::min);
+    let shift = if min_welfare <= 0.0 { 1.0 - min_welfare } else { 0.0 };
+    let objective: Vec<f64> = welfare.iter().map(|w| w + shift).collect();
+
+    let mut solver = Simplex::new(&objective, &constraints, &rhs)?;
+    let (_, x) = solver.solve()?;
+
+    let total: f64 = x.iter().sum();
+    if total < 1e-10 {
+        return Err(CorrelatedError::SolverError(SimplexError::Infeasible));
+    }
+
+    Ok(x.chunks(num_cols)
+        .map(|row| row.iter().map(|&p| p / total).collect())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    /// Largest gain any player gets by deviating from a recommendation.
+    fn max_incentive_violation(a: &[Vec<f64>], b: &[Vec<f64>], x: &[Vec<f64>]) -> f64 {
+        let (n, m) = (a.len(), a[0].len());
+        let row_gains = (0..n).flat_map(|i| {
+            (0..n).map(move |k| (0..m).map(|j| x[i][j] * (a[k][j] - a[i][j])).sum::<f64>())
+        });
+        let col_gains = (0..m).flat_map(|j| {
+            (0..m).map(move |l| (0..n).map(|i| x[i][j] * (b[i][l] - b[i][j])).sum::<f64>())
+        });
+        row_gains.chain(col_gains).fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    #[test]
+    fn test_coordination_game() {
+        // Battle of the sexes: both outcomes on the diagonal are efficient
+        let a = vec![vec![2.0, 0.0], vec![0.0, 1.0]];
+        let b = vec![vec![1.0, 0.0], vec![0.0, 2.0]];
+
+        let x = correlated_equilibrium(&a, &b).unwrap();
+
+        assert_relative_eq!(x[0][1], 0.0, epsilon = 1e-9);
+        assert_relative_eq!(x[1][0], 0.0, epsilon = 1e-9);
+        assert_relative_eq!(x[0][0] + x[1][1], 1.0, epsilon = 1e-9);
+        assert!(max_incentive_violation(&a, &b, &x) < 1e-9);
+    }
+
+    #[test]
+    fn test_chicken_beats_mixed_nash() {
+        // Mixed Nash of chicken yields 14/3 each; the best CE yields 21/4 each
+        let a = vec![vec![6.0, 2.0], vec![7.0, 0.0]];
+        let b = vec![vec![6.0, 7.0], vec![2.0, 0.0]];
+
+        let x = correlated_equilibrium(&a, &b).unwrap();
+        let welfare: f64 = (0..2)
+            .flat_map(|i| (0..2).map(move |j| (i, j)))
+            .map(|(i, j)| x[i][j] * (a[i][j] + b[i][j]))
+            .sum();
+
+        assert_relative_eq!(welfare, 10.5, epsilon = 1e-9);
+        assert_relative_eq!(x[1][1], 0.0, epsilon = 1e-9);
+        assert!(max_incentive_violation(&a, &b, &x) < 1e-9);
+    }
+
+    #[test]
+    fn test_mismatched_shapes_rejected() {
+        let a = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+        let b = vec![vec![1.0, 0.0]];
+
+        assert!(matches!(
+            correlated_equilibrium(&a, &b),
+            Err(CorrelatedError::DimensionMismatch)
+        ));
+    }
+}
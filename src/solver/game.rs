@@ -1,4 +1,6 @@
 use super::simplex::{Simplex, SimplexError};
+use super::SolverConfig;
+use std::time::Instant;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -9,17 +11,177 @@ pub enum GameError {
     InconsistentRows,
     #[error("Solver error: {0}")]
     SolverError(#[from] SimplexError),
+    #[error("Solver deadline exceeded")]
+    Timeout,
+    #[error("Column LP solution sums to {0}, too close to zero to normalize")]
+    DegenerateSolution(f64),
+    #[error("Solver produced an invalid strategy: {0}")]
+    InvalidSolution(#[from] VerificationError),
+    #[error("Payoff matrix is {rows}x{cols}, but this analysis needs 3x3")]
+    UnsupportedShape { rows: usize, cols: usize },
+    #[error("{actions} actions exceed the enumeration limit of {limit}")]
+    TooManyActions { actions: usize, limit: usize },
+    #[error("None of the {samples} sampled games could be solved")]
+    NoSolvedSamples { samples: usize },
+    #[error("Step size must be positive and finite, got {0}")]
+    InvalidStep(f64),
+}
+
+/// Tolerance used by `GameSolution::verify`.
+pub(crate) const VERIFY_TOLERANCE: f64 = 1e-6;
+
+/// Tikhonov weight added to the diagonal of rank-deficient systems.
+const REGULARIZATION: f64 = 1e-10;
+
+/// A condition violated by a claimed game solution.
+#[derive(Error, Debug, PartialEq)]
+pub enum VerificationError {
+    #[error("{player} strategy has {actual} entries, expected {expected}")]
+    DimensionMismatch { player: &'static str, expected: usize, actual: usize },
+    #[error("{player} strategy has invalid probability {probability} at index {index}")]
+    InvalidProbability { player: &'static str, index: usize, probability: f64 },
+    #[error("{player} strategy sums to {sum}, expected 1")]
+    NotNormalized { player: &'static str, sum: f64 },
+    #[error("Row strategy yields {payoff} against column {column}, below game value {value}")]
+    RowGuaranteeViolated { column: usize, payoff: f64, value: f64 },
+    #[error("Column strategy allows {payoff} against row {row}, above game value {value}")]
+    ColumnCapViolated { row: usize, payoff: f64, value: f64 },
 }
 
 /// Result of solving a two-player zero-sum game.
 #[derive(Debug, Clone)]
 pub struct GameSolution {
-    /// Optimal mixed strategy for Row player (maximizer)
+    /// Optimal mixed strategy for Row player
     pub row_strategy: Vec<f64>,
-    /// Optimal mixed strategy for Column player (minimizer)
+    /// Optimal mixed strategy for Column player
     pub col_strategy: Vec<f64>,
-    /// Value of the game
+    /// Value of the game to the maximizing player (see `Perspective`)
     pub game_value: f64,
+    /// Rows played with positive probability
+    ///
+    /// Falls back to every row when the solver did not determine it.
+    pub row_support: Vec<usize>,
+    /// Columns in the support of Column's LP solution
+    ///
+    /// Falls back to every column when the solver did not determine it.
+    pub col_support: Vec<usize>,
+    /// True if Row's indifference system was rank-deficient and Row's
+    /// strategy came from a regularized least-squares solve
+    pub regularized: bool,
+}
+
+impl GameSolution {
+    /// Returns true if both strategies and the value agree with `other` within `tol`.
+    ///
+    /// Supports and the `regularized` flag are not compared, since they
+    /// describe how a solution was found rather than what it is.
+    pub fn approx_eq(&self, other: &GameSolution, tol: f64) -> bool {
+        (self.game_value - other.game_value).abs() <= tol
+            && strategies_match(&self.row_strategy, &other.row_strategy, tol)
+            && strategies_match(&self.col_strategy, &other.col_strategy, tol)
+    }
+
+    /// Returns true if both players use the same mix, within `tol` per action.
+    ///
+    /// Games with a symmetric payoff matrix have such equilibria, so this is
+    /// a quick sanity check on the solver.
+    pub fn is_symmetric_equilibrium(&self, tol: f64) -> bool {
+        strategies_match(&self.row_strategy, &self.col_strategy, tol)
+    }
+
+    /// Verifies that this is an optimal solution of `matrix`.
+    ///
+    /// Checks that both strategies are probability vectors, that the row
+    /// strategy guarantees at least `game_value` against every column, and
+    /// that the column strategy holds every row to at most `game_value`.
+    pub fn verify(&self, matrix: &[Vec<f64>]) -> Result<(), VerificationError> {
+        let num_rows = matrix.len();
+        let num_cols = matrix.first().map_or(0, |row| row.len());
+
+        check_distribution("Row", &self.row_strategy, num_rows)?;
+        check_distribution("Column", &self.col_strategy, num_cols)?;
+
+        for j in 0..num_cols {
+            let payoff: f64 = matrix.iter().zip(&self.row_strategy).map(|(row, p)| p * row[j]).sum();
+            if payoff < self.game_value - VERIFY_TOLERANCE {
+                return Err(VerificationError::RowGuaranteeViolated {
+                    column: j,
+                    payoff,
+                    value: self.game_value,
+                });
+            }
+        }
+
+        for (i, row) in matrix.iter().enumerate() {
+            let payoff: f64 = row.iter().zip(&self.col_strategy).map(|(a, q)| a * q).sum();
+            if payoff > self.game_value + VERIFY_TOLERANCE {
+                return Err(VerificationError::ColumnCapViolated {
+                    row: i,
+                    payoff,
+                    value: self.game_value,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that `strategy` is a probability vector of length `expected`.
+fn check_distribution(
+    player: &'static str,
+    strategy: &[f64],
+    expected: usize,
+) -> Result<(), VerificationError> {
+    if strategy.len() != expected {
+        return Err(VerificationError::DimensionMismatch {
+            player,
+            expected,
+            actual: strategy.len(),
+        });
+    }
+
+    for (index, &probability) in strategy.iter().enumerate() {
+        if !probability.is_finite() || probability < -VERIFY_TOLERANCE {
+            return Err(VerificationError::InvalidProbability { player, index, probability });
+        }
+    }
+
+    let sum: f64 = strategy.iter().sum();
+    if (sum - 1.0).abs() > VERIFY_TOLERANCE {
+        return Err(VerificationError::NotNormalized { player, sum });
+    }
+
+    Ok(())
+}
+
+/// The linear program `GameSolver` hands to Simplex, for debugging.
+///
+/// Column's LP is `maximize objective · z` subject to
+/// `constraints · z <= rhs`, `z >= 0`, where `constraints` is the payoff
+/// matrix plus `shift`.
+#[derive(Debug, Clone)]
+pub struct LpDescription {
+    /// Amount added to every payoff to make the matrix positive
+    pub shift: f64,
+    /// Objective coefficients
+    pub objective: Vec<f64>,
+    /// Constraint matrix (the shifted payoff matrix)
+    pub constraints: Vec<Vec<f64>>,
+    /// Right-hand side of the constraints
+    pub rhs: Vec<f64>,
+    /// Simplex tableau after the last pivot
+    pub final_tableau: Vec<Vec<f64>>,
+}
+
+/// Which player's payoffs a `GameSolver` was given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Perspective {
+    /// Entries are Row's gains; Row maximizes and Column minimizes
+    #[default]
+    RowMaximizer,
+    /// Entries are Column's gains; Column maximizes and Row minimizes
+    ColMaximizer,
 }
 
 /// Solver for two-player zero-sum games using linear programming.
@@ -27,9 +189,15 @@ pub struct GameSolution {
 /// Finds the optimal mixed strategies and game value using the Simplex method.
 #[derive(Debug, Clone)]
 pub struct GameSolver {
+    /// Row's payoffs, whatever the perspective
     payoff_matrix: Vec<Vec<f64>>,
+    perspective: Perspective,
     num_rows: usize,
     num_cols: usize,
+    deadline: Option<Instant>,
+    /// Simplex basis to start Column's LP from
+    warm_basis: Option<Vec<usize>>,
+    config: SolverConfig,
 }
 
 impl GameSolver {
@@ -56,11 +224,93 @@ impl GameSolver {
 
         Ok(Self {
             payoff_matrix,
+            perspective: Perspective::RowMaximizer,
             num_rows,
             num_cols,
+            deadline: None,
+            warm_basis: None,
+            config: SolverConfig::default(),
         })
     }
 
+    /// Sets the tolerances and iteration limit used when solving.
+    pub fn with_config(mut self, config: SolverConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Creates a solver where Column maximizes `payoff_matrix[row][col]`.
+    ///
+    /// Useful for goalkeeper-centric matrices of saves or losses. Strategies
+    /// keep their Row/Column meaning and `game_value` is Column's value.
+    /// Internally the matrix is negated into Row's payoffs, which is what
+    /// `payoff_matrix` and `expected_payoff` report.
+    pub fn new_col_maximizer(payoff_matrix: Vec<Vec<f64>>) -> Result<Self, GameError> {
+        let row_payoffs = payoff_matrix
+            .iter()
+            .map(|row| row.iter().map(|v| -v).collect())
+            .collect();
+
+        Ok(Self {
+            perspective: Perspective::ColMaximizer,
+            ..Self::new(row_payoffs)?
+        })
+    }
+
+    /// Returns which player maximizes the matrix this solver was built from.
+    pub fn perspective(&self) -> Perspective {
+        self.perspective
+    }
+
+    /// Solves each matrix independently, e.g. for benchmarks and stress tests.
+    pub fn solve_many(matrices: &[Vec<Vec<f64>>]) -> Vec<Result<GameSolution, GameError>> {
+        matrices
+            .iter()
+            .map(|matrix| Self::new(matrix.clone()).and_then(|solver| solver.solve()))
+            .collect()
+    }
+
+    /// Solves the game starting Simplex from the basis of a nearby solution.
+    ///
+    /// The basis is recovered from `prev`'s supports: Column's support plus
+    /// the slack of every row Row does not play. For a slightly perturbed
+    /// matrix this is usually still optimal or a pivot or two away. If the
+    /// basis does not fit the new matrix the solve starts cold instead.
+    pub fn solve_warm(&self, prev: &GameSolution) -> Result<GameSolution, GameError> {
+        let basis = prev
+            .col_strategy
+            .iter()
+            .enumerate()
+            .filter(|&(_, &q)| q > self.config.zero_tol)
+            .map(|(j, _)| j)
+            .chain(
+                prev.row_strategy
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &p)| p <= self.config.zero_tol)
+                    .map(|(i, _)| self.num_cols + i),
+            )
+            .collect();
+
+        let solver = Self {
+            warm_basis: Some(basis),
+            ..self.clone()
+        };
+        solver.solve()
+    }
+
+    /// Solves the game, giving up with `GameError::Timeout` at `deadline`.
+    ///
+    /// The deadline is checked between Simplex pivots, so a pathological
+    /// matrix cannot keep the solver busy past it.
+    pub fn solve_with_deadline(&self, deadline: Instant) -> Result<GameSolution, GameError> {
+        let solver = Self {
+            deadline: Some(deadline),
+            ..self.clone()
+        };
+        solver.solve()
+    }
+
     /// Solves the game and returns optimal strategies for both players.
     pub fn solve(&self) -> Result<GameSolution, GameError> {
         // Shift the matrix to ensure all values are positive
@@ -68,18 +318,45 @@ impl GameSolver {
         let shifted_matrix = self.shift_matrix(shift);
 
         // Solve for Row player's strategy
-        let row_strategy = self.solve_row_player(&shifted_matrix)?;
+        let (row_strategy, col_support, regularized) = self.solve_row_player(&shifted_matrix)?;
+        let row_support = (0..self.num_rows).filter(|&i| row_strategy[i] > self.config.zero_tol).collect();
 
         // Solve for Column player's strategy
         let col_strategy = self.solve_col_player(&shifted_matrix)?;
 
+        // Reject NaN/Inf or unnormalized strategies rather than returning them
+        check_distribution("Row", &row_strategy, self.num_rows)?;
+        check_distribution("Column", &col_strategy, self.num_cols)?;
+        let row_strategy = normalized(&row_strategy);
+        let col_strategy = normalized(&col_strategy);
+
         // Calculate game value
-        let game_value = self.calculate_game_value(&row_strategy) ;
+        let game_value = self.to_maximizer(self.calculate_game_value(&row_strategy));
 
         Ok(GameSolution {
             row_strategy,
             col_strategy,
             game_value,
+            row_support,
+            col_support,
+            regularized,
+        })
+    }
+
+    /// Describes the LP that `solve` runs, including the final tableau.
+    pub fn debug_lp(&self) -> Result<LpDescription, GameError> {
+        let shift = self.calculate_shift();
+        let (objective, constraints, rhs) = self.column_lp(&self.shift_matrix(shift));
+
+        let mut solver = self.build_simplex(&objective, &constraints, &rhs)?;
+        solver.solve().map_err(simplex_error)?;
+
+        Ok(LpDescription {
+            shift,
+            final_tableau: solver.tableau().clone(),
+            objective,
+            constraints,
+            rhs,
         })
     }
 
@@ -118,7 +395,10 @@ impl GameSolver {
     ///            z_j >= 0
     ///
     /// The dual of this gives us Row player's strategy.
-    fn solve_row_player(&self, matrix: &[Vec<f64>]) -> Result<Vec<f64>, GameError> {
+    ///
+    /// Also returns the active columns, i.e. Column's support, and whether
+    /// the indifference system had to be regularized.
+    fn solve_row_player(&self, matrix: &[Vec<f64>]) -> Result<(Vec<f64>, Vec<usize>, bool), GameError> {
         // We solve via the Column player's problem and use duality.
         // For Row player with shifted positive matrix:
         // The value v = 1 / sum(z_j) where z is Column's optimal solution.
@@ -161,8 +441,8 @@ impl GameSolver {
         // Practical solution: solve Column's LP, compute game value,
         // then find Row's strategy that achieves this value.
 
-        let col_solution = self.solve_col_player_internal(matrix)?;
-        let sum_z: f64 = col_solution.iter().sum();
+        let (col_solution, duals) = self.solve_col_player_internal(matrix)?;
+        let sum_z = lp_sum(&col_solution, matrix, self.config.min_lp_sum)?;
         let game_value_shifted = 1.0 / sum_z;
 
         // Now find Row's strategy by solving:
@@ -179,10 +459,19 @@ impl GameSolver {
         let active_cols: Vec<usize> = col_solution
             .iter()
             .enumerate()
-            .filter(|&(_, &z)| z > 1e-9)
+            .filter(|&(_, &z)| z > self.config.zero_tol)
             .map(|(j, _)| j)
             .collect();
 
+        // By complementary slackness, Row only plays rows whose constraint
+        // sum_j(a_ij * z_j) <= 1 is tight; rows with slack get probability 0.
+        let active_rows: Vec<usize> = (0..self.num_rows)
+            .filter(|&i| {
+                let load: f64 = (0..self.num_cols).map(|j| matrix[i][j] * col_solution[j]).sum();
+                load > 1.0 - self.config.slack_tol
+            })
+            .collect();
+
         // Row's strategy must make Column indifferent among active columns.
         // For active columns j: sum_i(p_i * a_ij) = v (all equal)
         // For inactive columns: sum_i(p_i * a_ij) >= v
@@ -190,38 +479,62 @@ impl GameSolver {
         // Solve the system of linear equations for active columns.
         let num_active = active_cols.len();
 
-        if num_active == 0 {
+        if num_active == 0 || active_rows.is_empty() {
             return Err(GameError::SolverError(SimplexError::Infeasible));
         }
 
-        if num_active == 1 {
+        let candidate = if num_active == 1 {
             // Only one active column, Row plays pure best response
             let j = active_cols[0];
-            let best_row = (0..self.num_rows)
+            let best_row = active_rows
+                .iter()
+                .copied()
                 .max_by(|&i1, &i2| matrix[i1][j].partial_cmp(&matrix[i2][j]).unwrap())
                 .unwrap();
             let mut strategy = vec![0.0; self.num_rows];
             strategy[best_row] = 1.0;
-            return Ok(strategy);
-        }
-
-        // For multiple active columns, solve using the constraint that
-        // expected payoffs are equal for all active columns.
-        // We use: sum_i(p_i * a_ij) = v for active j, and sum(p_i) = 1
-
-        // This is a system of linear equations. Use Gaussian elimination.
-        let strategy = self.solve_indifference_system(matrix, &active_cols, game_value_shifted)?;
+            Ok((strategy, false))
+        } else {
+            // For multiple active columns, solve using the constraint that
+            // expected payoffs are equal for all active columns.
+            // We use: sum_i(p_i * a_ij) = v for active j, and sum(p_i) = 1
+
+            // This is a system of linear equations. Use Gaussian elimination.
+            self.solve_indifference_system(matrix, &active_rows, &active_cols, game_value_shifted)
+        };
+
+        // Degenerate games (e.g. duplicate rows) leave free variables the
+        // elimination can't pin down. The LP's dual prices are always an
+        // optimal strategy, so fall back to them when the candidate fails.
+        let (strategy, regularized) = match candidate {
+            Ok((strategy, regularized)) if guarantees(matrix, &strategy, game_value_shifted, self.config.zero_tol) => {
+                (strategy, regularized)
+            }
+            _ => {
+                let total: f64 = duals.iter().map(|y| y.max(0.0)).sum();
+                if total < self.config.min_weight {
+                    return Err(GameError::SolverError(SimplexError::Infeasible));
+                }
+                (duals.iter().map(|y| y.max(0.0) / total).collect(), false)
+            }
+        };
 
-        Ok(strategy)
+        Ok((strategy, active_cols, regularized))
     }
 
     /// Solves the system to find Row's strategy that makes Column indifferent.
+    ///
+    /// When the active columns are linearly dependent the system is
+    /// rank-deficient and elimination hits a vanishing pivot. It is then
+    /// solved in the least-squares sense with Tikhonov regularization,
+    /// which picks the minimum-norm solution, and the returned flag is set.
     fn solve_indifference_system(
         &self,
         matrix: &[Vec<f64>],
+        active_rows: &[usize],
         active_cols: &[usize],
         _game_value: f64,
-    ) -> Result<Vec<f64>, GameError> {
+    ) -> Result<(Vec<f64>, bool), GameError> {
         // We need: sum_i(p_i * a_ij) = same for all active j
         //          sum(p_i) = 1
         //          p_i >= 0
@@ -234,7 +547,7 @@ impl GameSolver {
 
         // General case: solve using least squares or direct system.
 
-        let n = self.num_rows;
+        let n = active_rows.len();
         let m = active_cols.len();
 
         // Build system: we want p such that A_active^T * p has all equal entries
@@ -250,8 +563,9 @@ impl GameSolver {
         for k in 1..m {
             let j0 = active_cols[0];
             let jk = active_cols[k];
-            let row: Vec<f64> = (0..n)
-                .map(|i| matrix[i][j0] - matrix[i][jk])
+            let row: Vec<f64> = active_rows
+                .iter()
+                .map(|&i| matrix[i][j0] - matrix[i][jk])
                 .collect();
             aug.push(row);
         }
@@ -263,31 +577,60 @@ impl GameSolver {
         let mut rhs: Vec<f64> = vec![0.0; m - 1];
         rhs.push(1.0);
 
-        // Solve using Gaussian elimination
-        let solution = gaussian_elimination(&mut aug, &mut rhs, n)?;
+        // Solve using Gaussian elimination, regularizing singular systems
+        let tol = self.config.elimination_tol;
+        let (solution, regularized) = match gaussian_elimination(&mut aug.clone(), &mut rhs.clone(), n, tol) {
+            Some(solution) => (solution, false),
+            None => (regularized_least_squares(&aug, &rhs, n, tol)?, true),
+        };
 
         // Ensure non-negative (clamp small negatives from numerical error)
-        let strategy: Vec<f64> = solution.iter().map(|&x| x.max(0.0)).collect();
+        // and place the active-row probabilities back into the full strategy
+        let mut strategy = vec![0.0; self.num_rows];
+        for (&i, &x) in active_rows.iter().zip(solution.iter()) {
+            strategy[i] = x.max(0.0);
+        }
 
         // Renormalize
         let sum: f64 = strategy.iter().sum();
-        if sum < 1e-10 {
+        if sum < self.config.min_weight {
             return Err(GameError::SolverError(SimplexError::Infeasible));
         }
 
-        Ok(strategy.iter().map(|&x| x / sum).collect())
+        Ok((strategy.iter().map(|&x| x / sum).collect(), regularized))
     }
 
-    /// Internal Column player solver that returns raw z values.
-    fn solve_col_player_internal(&self, matrix: &[Vec<f64>]) -> Result<Vec<f64>, GameError> {
-        let c: Vec<f64> = vec![1.0; self.num_cols];
-        let a: Vec<Vec<f64>> = matrix.to_vec();
-        let b: Vec<f64> = vec![1.0; self.num_rows];
+    /// Internal Column player solver that returns raw z values and the dual prices.
+    fn solve_col_player_internal(&self, matrix: &[Vec<f64>]) -> Result<(Vec<f64>, Vec<f64>), GameError> {
+        let (c, a, b) = self.column_lp(matrix);
 
-        let mut solver = Simplex::new(&c, &a, &b)?;
-        let (_, z) = solver.solve()?;
+        let mut solver = self.build_simplex(&c, &a, &b)?;
+        let (_, z) = solver.solve().map_err(simplex_error)?;
 
-        Ok(z)
+        Ok((z, solver.dual_values()))
+    }
+
+    /// Builds Column's LP `(c, A, b)`: maximize `sum(z_j)` s.t. `A z <= 1`.
+    fn column_lp(&self, matrix: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>, Vec<f64>) {
+        (vec![1.0; self.num_cols], matrix.to_vec(), vec![1.0; self.num_rows])
+    }
+
+    /// Sets up Simplex from the warm basis and deadline, if any.
+    fn build_simplex(&self, c: &[f64], a: &[Vec<f64>], b: &[f64]) -> Result<Simplex, GameError> {
+        let mut solver = match &self.warm_basis {
+            Some(basis) => Simplex::from_basis(c, a, b, basis).or_else(|_| Simplex::new(c, a, b))?,
+            None => Simplex::new(c, a, b)?,
+        }
+        .with_config(self.config);
+        if let Some(deadline) = self.deadline {
+            solver = solver.deadline(deadline);
+        }
+        Ok(solver)
+    }
+
+    /// Runs Simplex, honouring the solver's deadline if one is set.
+    fn run_simplex(&self, c: &[f64], a: &[Vec<f64>], b: &[f64]) -> Result<(f64, Vec<f64>), GameError> {
+        self.build_simplex(c, a, b)?.solve().map_err(simplex_error)
     }
 
     /// Solves for Column player's optimal mixed strategy.
@@ -305,18 +648,12 @@ impl GameSolver {
     fn solve_col_player(&self, matrix: &[Vec<f64>]) -> Result<Vec<f64>, GameError> {
         // For Column player: maximize sum(z_j)
         // Subject to: A * z <= 1
+        let (c, a, b) = self.column_lp(matrix);
 
-        let c: Vec<f64> = vec![1.0; self.num_cols];
-
-        let a: Vec<Vec<f64>> = matrix.to_vec();
-
-        let b: Vec<f64> = vec![1.0; self.num_rows];
-
-        let mut solver = Simplex::new(&c, &a, &b)?;
-        let (_, z) = solver.solve()?;
+        let (_, z) = self.run_simplex(&c, &a, &b)?;
 
         // Convert back: w = 1 / sum(z_j), q_j = z_j * w
-        let sum_z: f64 = z.iter().sum();
+        let sum_z = lp_sum(&z, matrix, self.config.min_lp_sum)?;
         let strategy: Vec<f64> = z.iter().map(|&zj| zj / sum_z).collect();
 
         Ok(strategy)
@@ -334,33 +671,196 @@ impl GameSolver {
             .fold(f64::INFINITY, f64::min)
     }
 
-    /// Returns the payoff matrix.
+    /// Converts a value to Row into a value to the maximizing player.
+    fn to_maximizer(&self, row_value: f64) -> f64 {
+        match self.perspective {
+            Perspective::RowMaximizer => row_value,
+            Perspective::ColMaximizer => -row_value,
+        }
+    }
+
+    /// Returns the (maximin, minimax) bounds implied by the solved strategies.
+    ///
+    /// The lower bound is the worst payoff Row's strategy can receive over
+    /// Column's actions; the upper bound is the best payoff Row could get
+    /// against Column's strategy. By the minimax theorem both equal the game
+    /// value, so a gap beyond rounding error signals a solver bug. Like
+    /// `game_value`, the bounds are from the maximizing player's side.
+    pub fn value_bounds(&self) -> Result<(f64, f64), GameError> {
+        let solution = self.solve()?;
+        let lower = self.calculate_game_value(&solution.row_strategy);
+        let upper = self
+            .payoff_matrix
+            .iter()
+            .map(|row| row.iter().zip(&solution.col_strategy).map(|(a, q)| a * q).sum::<f64>())
+            .fold(f64::NEG_INFINITY, f64::max);
+
+        match self.perspective {
+            Perspective::RowMaximizer => Ok((lower, upper)),
+            Perspective::ColMaximizer => Ok((-upper, -lower)),
+        }
+    }
+
+    /// Computes the game value from the Column player's LP alone.
+    ///
+    /// Unlike `solve`, this does not need to recover Row's strategy, so it
+    /// also works for degenerate games where that step is ill-conditioned.
+    pub(crate) fn value(&self) -> Result<f64, GameError> {
+        let shift = self.calculate_shift();
+        let shifted_matrix = self.shift_matrix(shift);
+        let (col_solution, _) = self.solve_col_player_internal(&shifted_matrix)?;
+        let sum_z = lp_sum(&col_solution, &shifted_matrix, self.config.min_lp_sum)?;
+        Ok(self.to_maximizer(1.0 / sum_z - shift))
+    }
+
+    /// Returns Row's Savage minimax-regret strategy.
+    ///
+    /// The regret of row `i` against column `j` is the best payoff Row could
+    /// have had against `j` minus `a_ij`. The returned strategy minimizes the
+    /// worst-case expected regret over Column's actions, found by solving the
+    /// zero-sum game whose payoffs are the negated regrets.
+    pub fn minimax_regret(&self) -> Result<Vec<f64>, GameError> {
+        let column_best: Vec<f64> = (0..self.num_cols)
+            .map(|j| {
+                self.payoff_matrix
+                    .iter()
+                    .map(|row| row[j])
+                    .fold(f64::NEG_INFINITY, f64::max)
+            })
+            .collect();
+        let negated_regret: Vec<Vec<f64>> = self
+            .payoff_matrix
+            .iter()
+            .map(|row| row.iter().zip(&column_best).map(|(a, best)| a - best).collect())
+            .collect();
+
+        let regret_game = Self {
+            payoff_matrix: negated_regret,
+            ..self.clone()
+        };
+        Ok(regret_game.solve()?.row_strategy)
+    }
+
+    /// Returns the rows weakly dominated by another row.
+    ///
+    /// Row `i` is weakly dominated if some other row pays at least as much
+    /// against every column and strictly more against at least one.
+    pub fn weakly_dominated_rows(&self) -> Vec<usize> {
+        let m = &self.payoff_matrix;
+        (0..self.num_rows)
+            .filter(|&i| {
+                (0..self.num_rows).any(|k| k != i && weakly_dominates(&m[k], &m[i]))
+            })
+            .collect()
+    }
+
+    /// Returns the columns weakly dominated by another column.
+    ///
+    /// Column `j` is weakly dominated if some other column concedes at most
+    /// as much against every row and strictly less against at least one.
+    pub fn weakly_dominated_cols(&self) -> Vec<usize> {
+        let columns: Vec<Vec<f64>> = (0..self.num_cols)
+            .map(|j| self.payoff_matrix.iter().map(|row| -row[j]).collect())
+            .collect();
+        (0..self.num_cols)
+            .filter(|&j| {
+                (0..self.num_cols).any(|l| l != j && weakly_dominates(&columns[l], &columns[j]))
+            })
+            .collect()
+    }
+
+    /// Returns Row's payoff matrix.
     pub fn payoff_matrix(&self) -> &Vec<Vec<f64>> {
         &self.payoff_matrix
     }
 
-    /// Calculates expected payoff for given strategies.
+    /// Calculates Row's expected payoff for given strategies.
     pub fn expected_payoff(&self, row_strategy: &[f64], col_strategy: &[f64]) -> f64 {
-        let mut payoff = 0.0;
-        for i in 0..self.num_rows {
-            for j in 0..self.num_cols {
-                payoff += row_strategy[i] * col_strategy[j] * self.payoff_matrix[i][j];
-            }
-        }
-        payoff
+        super::expected_payoff(&self.payoff_matrix, row_strategy, col_strategy)
     }
 }
 
+/// True if `a` and `b` have the same length and differ by at most `tol` everywhere.
+pub(crate) fn strategies_match(a: &[f64], b: &[f64], tol: f64) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| (x - y).abs() <= tol)
+}
+
+/// Sums Column's LP solution on `matrix`, refusing sums too small to divide by.
+///
+/// On the strictly positive shifted matrix every optimal solution has
+/// `sum(z_j) >= 1 / max(a_ij)`, so `min_sum` is compared against the sum
+/// scaled by `max(a_ij)` and games with large payoffs are not rejected.
+fn lp_sum(z: &[f64], matrix: &[Vec<f64>], min_sum: f64) -> Result<f64, GameError> {
+    let sum: f64 = z.iter().sum();
+    let max_entry = matrix.iter().flatten().cloned().fold(f64::NEG_INFINITY, f64::max);
+    if sum * max_entry >= min_sum {
+        Ok(sum)
+    } else {
+        Err(GameError::DegenerateSolution(sum))
+    }
+}
+
+/// Rescales a validated strategy to sum to exactly 1.
+///
+/// Rounding residue within the verification tolerance below zero is
+/// clamped away first, so no probability is returned negative.
+fn normalized(strategy: &[f64]) -> Vec<f64> {
+    let sum: f64 = strategy.iter().map(|p| p.max(0.0)).sum();
+    strategy.iter().map(|p| p.max(0.0) / sum).collect()
+}
+
+/// Reports a Simplex deadline as the game-level timeout.
+fn simplex_error(e: SimplexError) -> GameError {
+    match e {
+        SimplexError::Timeout => GameError::Timeout,
+        e => GameError::SolverError(e),
+    }
+}
+
+/// True if `a` is never worse than `b` and strictly better somewhere.
+fn weakly_dominates(a: &[f64], b: &[f64]) -> bool {
+    a.iter().zip(b).all(|(x, y)| x >= y) && a.iter().zip(b).any(|(x, y)| x > y)
+}
+
+/// Whether `strategy` holds every column of `matrix` to at least `value - tol`.
+fn guarantees(matrix: &[Vec<f64>], strategy: &[f64], value: f64, tol: f64) -> bool {
+    let num_cols = matrix.first().map_or(0, |row| row.len());
+    (0..num_cols).all(|j| {
+        let payoff: f64 = matrix.iter().zip(strategy).map(|(row, p)| p * row[j]).sum();
+        payoff >= value - tol
+    })
+}
+
+/// Solves `(A^T A + lambda I) x = A^T b` for `n` unknowns.
+///
+/// The regularized normal equations are nonsingular for any `A`, and for
+/// small `lambda` their solution approaches the minimum-norm least-squares
+/// solution of `A x = b`.
+fn regularized_least_squares(a: &[Vec<f64>], b: &[f64], n: usize, tol: f64) -> Result<Vec<f64>, GameError> {
+    let mut normal: Vec<Vec<f64>> = (0..n)
+        .map(|i| {
+            (0..n)
+                .map(|j| {
+                    let product: f64 = a.iter().map(|row| row[i] * row[j]).sum();
+                    if i == j { product + REGULARIZATION } else { product }
+                })
+                .collect()
+        })
+        .collect();
+    let mut projected: Vec<f64> = (0..n).map(|i| a.iter().zip(b).map(|(row, bk)| row[i] * bk).sum()).collect();
+
+    gaussian_elimination(&mut normal, &mut projected, n, tol).ok_or(GameError::SolverError(SimplexError::Infeasible))
+}
+
 /// Solves a system of linear equations using Gaussian elimination with partial pivoting.
-fn gaussian_elimination(
-    a: &mut [Vec<f64>],
-    b: &mut [f64],
-    n: usize,
-) -> Result<Vec<f64>, GameError> {
+///
+/// Returns `None` if a pivot falls below `tol`, i.e. the system is
+/// rank-deficient within its first `min(m, n)` columns.
+pub(crate) fn gaussian_elimination(a: &mut [Vec<f64>], b: &mut [f64], n: usize, tol: f64) -> Option<Vec<f64>> {
     let m = a.len(); // number of equations
 
     if m == 0 || n == 0 {
-        return Err(GameError::SolverError(SimplexError::InvalidDimensions));
+        return None;
     }
 
     // Forward elimination with partial pivoting
@@ -369,15 +869,15 @@ fn gaussian_elimination(
         let mut max_row = col;
         let mut max_val = if col < a.len() { a[col][col].abs() } else { 0.0 };
 
-        for row in (col + 1)..m {
-            if col < a[row].len() && a[row][col].abs() > max_val {
-                max_val = a[row][col].abs();
+        for (row, eq) in a.iter().enumerate().skip(col + 1) {
+            if col < eq.len() && eq[col].abs() > max_val {
+                max_val = eq[col].abs();
                 max_row = row;
             }
         }
 
-        if max_val < 1e-12 {
-            continue; // Skip this column (singular or underdetermined)
+        if max_val < tol {
+            return None;
         }
 
         // Swap rows
@@ -386,7 +886,7 @@ fn gaussian_elimination(
 
         // Eliminate below
         for row in (col + 1)..m {
-            if col < a[row].len() && a[col][col].abs() > 1e-12 {
+            if col < a[row].len() && a[col][col].abs() > tol {
                 let factor = a[row][col] / a[col][col];
                 for j in col..n {
                     if j < a[row].len() && j < a[col].len() {
@@ -402,7 +902,7 @@ fn gaussian_elimination(
     let mut x = vec![0.0; n];
 
     for i in (0..m.min(n)).rev() {
-        if i < a.len() && i < a[i].len() && a[i][i].abs() > 1e-12 {
+        if i < a.len() && i < a[i].len() && a[i][i].abs() > tol {
             let mut sum = b[i];
             for j in (i + 1)..n {
                 if j < a[i].len() {
@@ -413,13 +913,15 @@ fn gaussian_elimination(
         }
     }
 
-    Ok(x)
+    Some(x)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::random_matrix;
     use approx::assert_relative_eq;
+    use proptest::prelude::*;
 
     #[test]
     fn test_matching_pennies() {
@@ -441,6 +943,238 @@ mod tests {
         assert_relative_eq!(solution.game_value, 0.0, epsilon = 0.01);
     }
 
+    #[test]
+    fn test_debug_lp_describes_shifted_matrix() {
+        let pennies = vec![vec![1.0, -1.0], vec![-1.0, 1.0]];
+        let lp = GameSolver::new(pennies).unwrap().debug_lp().unwrap();
+
+        assert_eq!(lp.shift, 2.0);
+        assert_eq!(lp.constraints, vec![vec![3.0, 1.0], vec![1.0, 3.0]]);
+        assert_eq!(lp.objective, vec![1.0, 1.0]);
+        assert_eq!(lp.rhs, vec![1.0, 1.0]);
+        // Two constraint rows plus the objective row; z, slacks and RHS columns
+        assert_eq!(lp.final_tableau.len(), 3);
+        assert!(lp.final_tableau.iter().all(|row| row.len() == 5));
+        // Optimal sum(z) is 1 / (shifted value) = 1 / 2
+        assert_relative_eq!(lp.final_tableau[2][4], 0.5, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_degenerate_lp_sum_is_an_error() {
+        let matrix = vec![vec![1.0, 3.0], vec![2.0, 1.0]];
+        let min_sum = SolverConfig::default().min_lp_sum;
+        assert!(matches!(lp_sum(&[0.0, 0.0], &matrix, min_sum), Err(GameError::DegenerateSolution(sum)) if sum == 0.0));
+        assert!(matches!(lp_sum(&[f64::NAN, 0.2], &matrix, min_sum), Err(GameError::DegenerateSolution(_))));
+
+        // Large payoffs give small sums, but never below 1 / max(a_ij)
+        let huge = vec![vec![1e15, 3e15], vec![2e15, 1e15]];
+        assert!(lp_sum(&[2e-16, 2e-16], &huge, min_sum).is_ok());
+        // The sum check passes; with the default zero tolerance the LP itself
+        // fails (see test_zero_tol_does_not_loosen_tight_rows for a tuned config)
+        assert!(matches!(
+            GameSolver::new(huge).unwrap().solve(),
+            Err(GameError::SolverError(SimplexError::Infeasible))
+        ));
+
+        // Shifting by f64::MAX overflows to infinity, so the LP's only
+        // feasible point is z = 0
+        let overflow = vec![vec![f64::MAX, -f64::MAX], vec![-f64::MAX, f64::MAX]];
+        let solver = GameSolver::new(overflow).unwrap();
+        assert!(matches!(solver.solve(), Err(GameError::DegenerateSolution(sum)) if sum == 0.0));
+        let shifted = solver.shift_matrix(solver.calculate_shift());
+        assert!(matches!(solver.solve_col_player(&shifted), Err(GameError::DegenerateSolution(sum)) if sum == 0.0));
+    }
+
+    #[test]
+    fn test_solve_many_random_matrices() {
+        let matrices: Vec<_> = (0..100).map(|seed| random_matrix(3, 3, seed)).collect();
+
+        for result in GameSolver::solve_many(&matrices) {
+            let solution = result.unwrap();
+            assert_relative_eq!(solution.row_strategy.iter().sum::<f64>(), 1.0, epsilon = 1e-9);
+            assert_relative_eq!(solution.col_strategy.iter().sum::<f64>(), 1.0, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_symmetric_equilibrium() {
+        let pennies = vec![vec![1.0, -1.0], vec![-1.0, 1.0]];
+        let solution = GameSolver::new(pennies).unwrap().solve().unwrap();
+        assert!(solution.is_symmetric_equilibrium(1e-9));
+
+        let saddle = vec![vec![1.0, 5.0], vec![3.0, 4.0]];
+        let solution = GameSolver::new(saddle).unwrap().solve().unwrap();
+        assert!(!solution.is_symmetric_equilibrium(1e-9));
+    }
+
+    #[test]
+    fn test_col_maximizer_perspective() {
+        let a = vec![vec![3.0, -1.0, 0.5], vec![-2.0, 4.0, 1.0]];
+        let row_view = GameSolver::new(a.clone()).unwrap().solve().unwrap();
+
+        // Column gaining -A is the same game with the value seen from Column
+        let negated: Vec<Vec<f64>> = a.iter().map(|row| row.iter().map(|v| -v).collect()).collect();
+        let col_solver = GameSolver::new_col_maximizer(negated).unwrap();
+        let col_view = col_solver.solve().unwrap();
+        assert_eq!(col_solver.perspective(), Perspective::ColMaximizer);
+        assert_relative_eq!(col_view.game_value, -row_view.game_value, epsilon = 1e-9);
+        for (x, y) in col_view.row_strategy.iter().zip(&row_view.row_strategy) {
+            assert_relative_eq!(x, y, epsilon = 1e-9);
+        }
+
+        // Column gaining A^T swaps the roles, mirroring the strategies
+        let transposed: Vec<Vec<f64>> = (0..3).map(|j| a.iter().map(|row| row[j]).collect()).collect();
+        let mirrored = GameSolver::new_col_maximizer(transposed).unwrap().solve().unwrap();
+        assert_relative_eq!(mirrored.game_value, row_view.game_value, epsilon = 1e-9);
+        for (x, y) in mirrored.col_strategy.iter().zip(&row_view.row_strategy) {
+            assert_relative_eq!(x, y, epsilon = 1e-9);
+        }
+        for (x, y) in mirrored.row_strategy.iter().zip(&row_view.col_strategy) {
+            assert_relative_eq!(x, y, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_solution_support() {
+        let pennies = vec![vec![1.0, -1.0], vec![-1.0, 1.0]];
+        let solution = GameSolver::new(pennies).unwrap().solve().unwrap();
+        assert_eq!(solution.row_support, vec![0, 1]);
+        assert_eq!(solution.col_support, vec![0, 1]);
+
+        // Saddle point at row 1, column 0
+        let saddle = vec![vec![1.0, 5.0], vec![3.0, 4.0]];
+        let solution = GameSolver::new(saddle).unwrap().solve().unwrap();
+        assert_eq!(solution.row_support, vec![1]);
+        assert_eq!(solution.col_support, vec![0]);
+    }
+
+    #[test]
+    fn test_dominated_row_gets_zero_probability() {
+        // Middle row is strictly dominated and must not be played
+        let matrix = vec![
+            vec![0.16, 0.86, 0.90],
+            vec![-0.40, -0.40, -0.40],
+            vec![0.86, 0.80, 0.20],
+        ];
+
+        let solver = GameSolver::new(matrix).unwrap();
+        let solution = solver.solve().unwrap();
+
+        assert_relative_eq!(solution.row_strategy[1], 0.0, epsilon = 1e-9);
+        assert_relative_eq!(solution.row_strategy.iter().sum::<f64>(), 1.0, epsilon = 1e-9);
+        assert!(solution.game_value > 0.4);
+    }
+
+    #[test]
+    fn test_weakly_dominated() {
+        // Row 1 ties row 0 against column 0 but is worse against column 1;
+        // column 2 concedes at least as much as column 0 everywhere
+        let matrix = vec![
+            vec![0.5, 0.8, 0.9],
+            vec![0.5, 0.6, 0.7],
+            vec![0.9, 0.2, 0.9],
+        ];
+
+        let solver = GameSolver::new(matrix).unwrap();
+
+        assert_eq!(solver.weakly_dominated_rows(), vec![1]);
+        assert_eq!(solver.weakly_dominated_cols(), vec![2]);
+    }
+
+    #[test]
+    fn test_verify_default_data() {
+        let pk = crate::football::penalty::PenaltyKick::with_default_data();
+        let matrix = pk.payoff_matrix().to_expected_payoff();
+
+        let solution = GameSolver::new(matrix.clone()).unwrap().solve().unwrap();
+
+        assert_eq!(solution.verify(&matrix), Ok(()));
+    }
+
+    #[test]
+    fn test_value_bounds_agree() {
+        let pk = crate::football::penalty::PenaltyKick::with_default_data();
+        let solver = GameSolver::new(pk.payoff_matrix().to_expected_payoff()).unwrap();
+
+        let value = solver.solve().unwrap().game_value;
+        let (lower, upper) = solver.value_bounds().unwrap();
+
+        assert_relative_eq!(lower, value, epsilon = 1e-9);
+        assert_relative_eq!(upper, value, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_verify_corrupted_solution() {
+        let matrix = vec![
+            vec![1.0, -1.0],
+            vec![-1.0, 1.0],
+        ];
+        let mut solution = GameSolver::new(matrix.clone()).unwrap().solve().unwrap();
+
+        // Row leaning on its first action can be exploited by the second column
+        solution.row_strategy = vec![0.8, 0.2];
+        assert!(matches!(
+            solution.verify(&matrix),
+            Err(VerificationError::RowGuaranteeViolated { column: 1, .. })
+        ));
+
+        solution.row_strategy = vec![0.8, 0.3];
+        assert!(matches!(
+            solution.verify(&matrix),
+            Err(VerificationError::NotNormalized { player: "Row", .. })
+        ));
+    }
+
+    #[test]
+    fn test_minimax_regret_differs_from_nash() {
+        // Nash plays the low-stakes row 3:1; regret weighs the missed 3
+        let matrix = vec![vec![3.0, 0.0], vec![0.0, 1.0]];
+        let solver = GameSolver::new(matrix).unwrap();
+
+        let nash = solver.solve().unwrap().row_strategy;
+        let regret = solver.minimax_regret().unwrap();
+
+        assert_relative_eq!(nash[0], 0.25, epsilon = 1e-9);
+        assert_relative_eq!(regret[0], 0.75, epsilon = 1e-9);
+        assert_relative_eq!(regret[1], 0.25, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_warm_start_matches_cold_solve() {
+        let pk = crate::football::penalty::PenaltyKick::with_default_data();
+        let matrix = pk.payoff_matrix().to_expected_payoff();
+        let base = GameSolver::new(matrix.clone()).unwrap().solve().unwrap();
+
+        let mut perturbed = matrix;
+        perturbed[1][1] += 0.02;
+        let solver = GameSolver::new(perturbed).unwrap();
+
+        let cold = solver.solve().unwrap();
+        let warm = solver.solve_warm(&base).unwrap();
+
+        assert_relative_eq!(warm.game_value, cold.game_value, epsilon = 1e-9);
+        for (w, c) in warm.row_strategy.iter().zip(&cold.row_strategy) {
+            assert_relative_eq!(*w, *c, epsilon = 1e-9);
+        }
+        for (w, c) in warm.col_strategy.iter().zip(&cold.col_strategy) {
+            assert_relative_eq!(*w, *c, epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_expired_deadline_times_out() {
+        let solver = GameSolver::new(vec![vec![3.0, -1.0], vec![-2.0, 4.0]]).unwrap();
+        let start = Instant::now();
+
+        let result = solver.solve_with_deadline(start);
+
+        assert!(matches!(result, Err(GameError::Timeout)));
+        assert!(start.elapsed().as_millis() < 100);
+
+        let far = start + std::time::Duration::from_secs(60);
+        assert!(solver.solve_with_deadline(far).is_ok());
+    }
+
     #[test]
     fn test_asymmetric_game() {
         // Asymmetric 2x3 game
@@ -466,4 +1200,119 @@ mod tests {
             assert!(q >= -0.01);
         }
     }
+
+    #[test]
+    fn test_degenerate_games_solve() {
+        // Found by the property test below: duplicate rows leave the
+        // indifference system underdetermined, and a degenerate pivot left a
+        // reduced cost and RHS rounded just below zero
+        let duplicate_rows = vec![vec![0.0, 2.0], vec![0.0, 2.0], vec![1.0, 0.0]];
+        let degenerate_pivot = vec![
+            vec![2.0, 0.0, 0.0, 2.0, 0.0, 0.0],
+            vec![-1.0, 1.0, 0.0, -2.0, 0.0, 0.0],
+        ];
+
+        for matrix in [duplicate_rows, degenerate_pivot] {
+            let solution = GameSolver::new(matrix.clone()).unwrap().solve().unwrap();
+            assert_eq!(solution.verify(&matrix), Ok(()));
+        }
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let solution = GameSolver::new(vec![vec![3.0, -1.0], vec![-2.0, 4.0]]).unwrap().solve().unwrap();
+        let mut perturbed = solution.clone();
+        perturbed.row_strategy[0] += 1e-6;
+        perturbed.row_strategy[1] -= 1e-6;
+        perturbed.game_value += 5e-7;
+
+        assert!(solution.approx_eq(&solution, 0.0));
+        assert!(solution.approx_eq(&perturbed, 1e-5));
+        assert!(!solution.approx_eq(&perturbed, 1e-8));
+
+        perturbed.col_strategy.push(0.0);
+        assert!(!solution.approx_eq(&perturbed, 1.0));
+    }
+
+    #[test]
+    fn test_config_for_scaled_matrix() {
+        // Scaled by 1e9, Column's LP variables are ~1e-13, below the default zero tolerance
+        let matrix: Vec<Vec<f64>> = vec![
+            vec![0.58, 0.93, 0.95],
+            vec![0.83, 0.44, 0.83],
+            vec![0.93, 0.90, 0.60],
+        ];
+        let scaled: Vec<Vec<f64>> = matrix.iter().map(|row| row.iter().map(|v| v * 1e9).collect()).collect();
+
+        assert!(GameSolver::new(scaled.clone()).unwrap().solve().is_err());
+
+        let config = SolverConfig { zero_tol: 1e-14, ..SolverConfig::default() };
+        let solution = GameSolver::new(scaled).unwrap().with_config(config).solve().unwrap();
+        let expected = GameSolver::new(matrix).unwrap().solve().unwrap();
+
+        assert_relative_eq!(solution.game_value, expected.game_value * 1e9, max_relative = 1e-9);
+        assert!(strategies_match(&solution.row_strategy, &expected.row_strategy, 1e-9));
+        assert!(strategies_match(&solution.col_strategy, &expected.col_strategy, 1e-9));
+    }
+
+    #[test]
+    fn test_zero_tol_does_not_loosen_tight_rows() {
+        // LP variables near 1e-16 need a tiny zero tolerance, but the
+        // tight-row check keeps its own slack tolerance
+        let huge = vec![vec![1e15, 3e15], vec![2e15, 1e15]];
+        let config = SolverConfig { zero_tol: 1e-20, ..SolverConfig::default() };
+        let solution = GameSolver::new(huge.clone()).unwrap().with_config(config).solve().unwrap();
+
+        assert_relative_eq!(solution.game_value, 5e15 / 3.0, max_relative = 1e-9);
+        assert_relative_eq!(solution.row_strategy[0], 1.0 / 3.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_rank_deficient_system_regularized() {
+        // Rows 0 and 1 are identical, so the indifference system has two
+        // equations for three unknowns; regularization splits them evenly
+        let matrix = vec![vec![0.0, 2.0], vec![0.0, 2.0], vec![1.0, 0.0]];
+        let solution = GameSolver::new(matrix.clone()).unwrap().solve().unwrap();
+
+        assert!(solution.regularized);
+        assert_eq!(solution.verify(&matrix), Ok(()));
+        assert_relative_eq!(solution.row_strategy[0], 1.0 / 6.0, epsilon = 1e-6);
+        assert_relative_eq!(solution.row_strategy[1], 1.0 / 6.0, epsilon = 1e-6);
+        assert_relative_eq!(solution.game_value, 2.0 / 3.0, epsilon = 1e-6);
+
+        // Duplicate columns once made Simplex report both as basic
+        let matrix = vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 1.0]];
+        let solution = GameSolver::new(matrix.clone()).unwrap().solve().unwrap();
+        assert_eq!(solution.verify(&matrix), Ok(()));
+        assert!(!GameSolver::new(vec![vec![1.0, 0.0], vec![0.0, 1.0]]).unwrap().solve().unwrap().regularized);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(500))]
+
+        #[test]
+        fn prop_solve_returns_valid_equilibrium(
+            matrix in (1usize..=6, 1usize..=6).prop_flat_map(|(rows, cols)| {
+                // Small integers give the ties and duplicate rows of degenerate games
+                let continuous = prop::collection::vec(prop::collection::vec(-100.0f64..100.0, cols), rows);
+                let integer = prop::collection::vec(
+                    prop::collection::vec((-2i32..=2).prop_map(f64::from), cols),
+                    rows,
+                );
+                prop_oneof![continuous, integer]
+            })
+        ) {
+            let solution = GameSolver::new(matrix.clone()).unwrap().solve().unwrap();
+
+            for strategy in [&solution.row_strategy, &solution.col_strategy] {
+                prop_assert!(strategy.iter().all(|&p| p.is_finite() && p >= 0.0), "{:?}", strategy);
+                prop_assert!((strategy.iter().sum::<f64>() - 1.0).abs() < 1e-9, "{:?}", strategy);
+            }
+
+            let min = matrix.iter().flatten().cloned().fold(f64::INFINITY, f64::min);
+            let max = matrix.iter().flatten().cloned().fold(f64::NEG_INFINITY, f64::max);
+            prop_assert!(min - 1e-9 <= solution.game_value && solution.game_value <= max + 1e-9);
+            prop_assert_eq!(solution.verify(&matrix), Ok(()));
+        }
+    }
 }
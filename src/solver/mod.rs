@@ -1,3 +1,102 @@
 pub mod simplex;
 pub mod game;
 pub mod nash;
+pub mod quantal;
+pub mod correlated;
+
+/// Numerical tolerances shared by `GameSolver` and `Simplex`.
+///
+/// The defaults suit payoffs of order 1, such as success rates. Matrices
+/// with much larger or smaller entries produce LP variables scaled by the
+/// reciprocal and may need tolerances scaled to match.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolverConfig {
+    /// Tableau entries smaller than this in magnitude are not pivoted on,
+    /// and reduced costs above `-pivot_tol` count as optimal
+    pub pivot_tol: f64,
+    /// LP variables and probabilities at or below this are treated as zero
+    pub zero_tol: f64,
+    /// Row strategies whose weights sum below this cannot be normalized
+    pub min_weight: f64,
+    /// Constraints of Column's LP within this of their bound count as tight
+    pub slack_tol: f64,
+    /// Smallest `sum(z_j) * max(a_ij)` of Column's LP solution that may be normalized
+    pub min_lp_sum: f64,
+    /// Pivots smaller than this mark the indifference system as rank-deficient
+    pub elimination_tol: f64,
+    /// Pivots Simplex may perform before giving up
+    pub max_iterations: usize,
+}
+
+impl SolverConfig {
+    /// Creates the default tolerances, suited to payoffs of order 1.
+    pub fn new() -> Self {
+        Self {
+            pivot_tol: 1e-10,
+            zero_tol: 1e-9,
+            min_weight: 1e-10,
+            slack_tol: 1e-9,
+            min_lp_sum: 1e-12,
+            elimination_tol: 1e-12,
+            max_iterations: 1000,
+        }
+    }
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Row's expected payoff when both players mix over `matrix`.
+///
+/// Iterates only over entries present in the matrix and both strategies,
+/// so mismatched lengths or an empty matrix contribute nothing.
+pub fn expected_payoff(matrix: &[Vec<f64>], row_strategy: &[f64], col_strategy: &[f64]) -> f64 {
+    matrix
+        .iter()
+        .zip(row_strategy)
+        .map(|(row, &p)| p * row.iter().zip(col_strategy).map(|(a, &q)| a * q).sum::<f64>())
+        .sum()
+}
+
+/// Expected payoff (to Row) of each row against `col_strategy`.
+pub(crate) fn row_payoffs(matrix: &[Vec<f64>], col_strategy: &[f64]) -> Vec<f64> {
+    matrix
+        .iter()
+        .map(|row| row.iter().zip(col_strategy).map(|(a, q)| a * q).sum())
+        .collect()
+}
+
+/// Expected payoff (to Row) of each column against `row_strategy`.
+///
+/// An empty matrix has no columns and yields an empty vector.
+pub(crate) fn col_payoffs(matrix: &[Vec<f64>], row_strategy: &[f64]) -> Vec<f64> {
+    let num_cols = matrix.first().map_or(0, |row| row.len());
+    (0..num_cols)
+        .map(|j| matrix.iter().zip(row_strategy).map(|(row, p)| p * row[j]).sum())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expected_payoff() {
+        let matrix = vec![vec![1.0, -1.0], vec![-1.0, 1.0]];
+        assert_eq!(expected_payoff(&matrix, &[1.0, 0.0], &[0.25, 0.75]), -0.5);
+        assert_eq!(expected_payoff(&[], &[], &[]), 0.0);
+        assert_eq!(expected_payoff(&[], &[0.5, 0.5], &[1.0]), 0.0);
+    }
+
+    #[test]
+    fn test_pure_action_payoffs() {
+        let matrix = vec![vec![1.0, -1.0], vec![-1.0, 1.0]];
+        assert_eq!(row_payoffs(&matrix, &[0.25, 0.75]), vec![-0.5, 0.5]);
+        assert_eq!(col_payoffs(&matrix, &[1.0, 0.0]), vec![1.0, -1.0]);
+        assert!(row_payoffs(&[], &[1.0]).is_empty());
+        assert!(col_payoffs(&[], &[1.0]).is_empty());
+    }
+}
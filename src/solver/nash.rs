@@ -1,4 +1,23 @@
-use super::game::{GameSolution, GameSolver, GameError};
+use super::{col_payoffs, expected_payoff, row_payoffs};
+use super::game::{gaussian_elimination, GameSolution, GameSolver, GameError};
+use super::SolverConfig;
+
+/// Most actions per player `find_all` will enumerate supports for.
+const MAX_ENUMERATED_ACTIONS: usize = 20;
+
+/// Iteration cap for the regret-matching loop in `find_epsilon`.
+const MAX_REGRET_ITERATIONS: usize = 1_000_000;
+
+/// Approximate equilibrium found by regret minimization.
+#[derive(Debug, Clone)]
+pub struct ApproximateEquilibrium {
+    /// Time-averaged strategies; `game_value` is their expected payoff
+    pub solution: GameSolution,
+    /// Largest gain either player can get by deviating
+    pub achieved_epsilon: f64,
+    /// Regret-matching iterations performed
+    pub iterations: usize,
+}
 
 /// Nash equilibrium detector for two-player zero-sum games.
 ///
@@ -16,6 +35,146 @@ impl NashEquilibrium {
         Ok(Self { solution })
     }
 
+    /// Enumerates the Nash equilibria built from extreme optimal strategies.
+    ///
+    /// Degenerate games can have several optimal strategies for either
+    /// player. The extreme points of each player's optimal strategy set are
+    /// found by support enumeration, and since every pairing of optimal
+    /// strategies in a zero-sum game is an equilibrium, all combinations are
+    /// returned. Near-identical strategies are reported once.
+    ///
+    /// Every pair of a row support and a set of tight columns is tried, so
+    /// an `m x n` game solves up to `2^(m + n)` small linear systems. Games
+    /// with more than 20 actions for either player return
+    /// `GameError::TooManyActions`.
+    pub fn find_all(payoff_matrix: Vec<Vec<f64>>) -> Result<Vec<GameSolution>, GameError> {
+        Self::find_all_with_config(payoff_matrix, SolverConfig::default())
+    }
+
+    /// Like `find_all`, with explicit solver tolerances.
+    ///
+    /// `zero_tol` decides feasibility, support membership and which
+    /// strategies count as duplicates.
+    pub fn find_all_with_config(
+        payoff_matrix: Vec<Vec<f64>>,
+        config: SolverConfig,
+    ) -> Result<Vec<GameSolution>, GameError> {
+        let solver = GameSolver::new(payoff_matrix)?.with_config(config);
+        let matrix = solver.payoff_matrix();
+        let actions = matrix.len().max(matrix[0].len());
+        if actions > MAX_ENUMERATED_ACTIONS {
+            return Err(GameError::TooManyActions { actions, limit: MAX_ENUMERATED_ACTIONS });
+        }
+        let value = solver.value()?;
+        let tol = config.zero_tol;
+
+        let transposed: Vec<Vec<f64>> = (0..matrix[0].len())
+            .map(|j| matrix.iter().map(|row| -row[j]).collect())
+            .collect();
+
+        let row_strategies = extreme_optimal_strategies(matrix, value, tol);
+        let col_strategies = extreme_optimal_strategies(&transposed, -value, tol);
+
+        let mut solutions = Vec::new();
+        for row_strategy in &row_strategies {
+            for col_strategy in &col_strategies {
+                solutions.push(GameSolution {
+                    row_strategy: row_strategy.clone(),
+                    col_strategy: col_strategy.clone(),
+                    game_value: value,
+                    row_support: support(row_strategy, tol),
+                    col_support: support(col_strategy, tol),
+                    regularized: false,
+                });
+            }
+        }
+
+        Ok(solutions)
+    }
+
+    /// Finds an epsilon-Nash equilibrium by regret matching.
+    ///
+    /// Both players repeatedly play in proportion to their positive
+    /// cumulative regrets, and the time-averaged strategies converge to
+    /// equilibrium without solving an LP. The loop stops once the
+    /// exploitability of the averages drops below `epsilon`, or after a
+    /// fixed iteration cap, in which case `achieved_epsilon` is larger than
+    /// requested.
+    pub fn find_epsilon(
+        payoff_matrix: Vec<Vec<f64>>,
+        epsilon: f64,
+    ) -> Result<ApproximateEquilibrium, GameError> {
+        let solver = GameSolver::new(payoff_matrix)?;
+        let matrix = solver.payoff_matrix();
+        let num_rows = matrix.len();
+        let num_cols = matrix[0].len();
+
+        let mut row_regret = vec![0.0; num_rows];
+        let mut col_regret = vec![0.0; num_cols];
+        let mut row_total = vec![0.0; num_rows];
+        let mut col_total = vec![0.0; num_cols];
+
+        let mut achieved_epsilon = f64::INFINITY;
+        let mut iterations = 0;
+
+        while iterations < MAX_REGRET_ITERATIONS {
+            iterations += 1;
+
+            let row_strategy = regret_matching(&row_regret);
+            let col_strategy = regret_matching(&col_regret);
+
+            let row_values = row_payoffs(matrix, &col_strategy);
+            let col_values = col_payoffs(matrix, &row_strategy);
+            let current = expected_payoff(matrix, &row_strategy, &col_strategy);
+
+            // Regret matching+: cumulative regrets are floored at zero
+            for (regret, &u) in row_regret.iter_mut().zip(&row_values) {
+                *regret = (*regret + u - current).max(0.0);
+            }
+            for (regret, &u) in col_regret.iter_mut().zip(&col_values) {
+                *regret = (*regret + current - u).max(0.0);
+            }
+
+            for (total, p) in row_total.iter_mut().zip(&row_strategy) {
+                *total += p;
+            }
+            for (total, q) in col_total.iter_mut().zip(&col_strategy) {
+                *total += q;
+            }
+
+            let row_average = normalized(&row_total);
+            let col_average = normalized(&col_total);
+            let best_row = row_payoffs(matrix, &col_average)
+                .into_iter()
+                .fold(f64::NEG_INFINITY, f64::max);
+            let best_col = col_payoffs(matrix, &row_average)
+                .into_iter()
+                .fold(f64::INFINITY, f64::min);
+            achieved_epsilon = best_row - best_col;
+
+            if achieved_epsilon < epsilon {
+                break;
+            }
+        }
+
+        let row_strategy = normalized(&row_total);
+        let col_strategy = normalized(&col_total);
+        let game_value = solver.expected_payoff(&row_strategy, &col_strategy);
+
+        Ok(ApproximateEquilibrium {
+            solution: GameSolution {
+                row_support: (0..row_strategy.len()).collect(),
+                col_support: (0..col_strategy.len()).collect(),
+                row_strategy,
+                col_strategy,
+                game_value,
+                regularized: false,
+            },
+            achieved_epsilon,
+            iterations,
+        })
+    }
+
     /// Returns the equilibrium strategy for the Row player (maximizer).
     pub fn row_strategy(&self) -> &[f64] {
         &self.solution.row_strategy
@@ -35,61 +194,110 @@ impl NashEquilibrium {
     ///
     /// An epsilon-Nash equilibrium means neither player can improve
     /// their expected payoff by more than epsilon by unilaterally deviating.
+    /// A matrix without rows or columns has no equilibrium and returns false.
     pub fn is_epsilon_nash(
         payoff_matrix: &[Vec<f64>],
         row_strategy: &[f64],
         col_strategy: &[f64],
         epsilon: f64,
     ) -> bool {
-        let num_rows = payoff_matrix.len();
-        let num_cols = payoff_matrix[0].len();
+        if payoff_matrix.first().is_none_or(|row| row.is_empty()) {
+            return false;
+        }
 
         // Calculate current expected payoff
-        let current_payoff = Self::expected_payoff(payoff_matrix, row_strategy, col_strategy);
-
-        // Check if Row player can improve by deviating to any pure strategy
-        for i in 0..num_rows {
-            let mut pure_strategy = vec![0.0; num_rows];
-            pure_strategy[i] = 1.0;
-            let deviation_payoff = Self::expected_payoff(payoff_matrix, &pure_strategy, col_strategy);
-            if deviation_payoff > current_payoff + epsilon {
-                return false;
+        let current_payoff = expected_payoff(payoff_matrix, row_strategy, col_strategy);
+
+        // Neither Row can gain by a pure deviation, nor Column (who
+        // minimizes) reduce the payoff by one
+        row_payoffs(payoff_matrix, col_strategy)
+            .into_iter()
+            .all(|payoff| payoff <= current_payoff + epsilon)
+            && col_payoffs(payoff_matrix, row_strategy)
+                .into_iter()
+                .all(|payoff| payoff >= current_payoff - epsilon)
+    }
+
+    /// Returns the full solution details.
+    pub fn solution(&self) -> &GameSolution {
+        &self.solution
+    }
+}
+
+/// Returns a strategy proportional to the positive regrets, or uniform if
+/// no action has positive regret.
+pub(crate) fn regret_matching(regrets: &[f64]) -> Vec<f64> {
+    let positive: Vec<f64> = regrets.iter().map(|&r| r.max(0.0)).collect();
+    if positive.iter().sum::<f64>() > 0.0 {
+        normalized(&positive)
+    } else {
+        vec![1.0 / regrets.len() as f64; regrets.len()]
+    }
+}
+
+/// Indices played with probability above `tol`.
+fn support(strategy: &[f64], tol: f64) -> Vec<usize> {
+    (0..strategy.len()).filter(|&i| strategy[i] > tol).collect()
+}
+
+fn normalized(weights: &[f64]) -> Vec<f64> {
+    let total: f64 = weights.iter().sum();
+    weights.iter().map(|w| w / total).collect()
+}
+
+/// Finds the extreme points of the maximizer's optimal strategy set.
+///
+/// Each vertex is determined by a support `S` together with `|S| - 1`
+/// columns held at exactly the game value.
+fn extreme_optimal_strategies(matrix: &[Vec<f64>], value: f64, tol: f64) -> Vec<Vec<f64>> {
+    let num_rows = matrix.len();
+    let num_cols = matrix.first().map_or(0, |row| row.len());
+    let mut strategies: Vec<Vec<f64>> = Vec::new();
+
+    for support_mask in 1u64..(1 << num_rows) {
+        let support: Vec<usize> = (0..num_rows).filter(|i| support_mask & (1 << i) != 0).collect();
+
+        for tight_mask in 0u64..(1 << num_cols) {
+            if tight_mask.count_ones() as usize != support.len() - 1 {
+                continue;
             }
-        }
+            let tight: Vec<usize> = (0..num_cols).filter(|j| tight_mask & (1 << j) != 0).collect();
 
-        // Check if Column player can improve by deviating to any pure strategy
-        for j in 0..num_cols {
-            let mut pure_strategy = vec![0.0; num_cols];
-            pure_strategy[j] = 1.0;
-            let deviation_payoff = Self::expected_payoff(payoff_matrix, row_strategy, &pure_strategy);
-            // Column wants to minimize, so check if they can reduce payoff
-            if deviation_payoff < current_payoff - epsilon {
-                return false;
+            // Probabilities sum to one and every tight column pays exactly the value
+            let mut a = vec![vec![1.0; support.len()]];
+            let mut b = vec![1.0];
+            for &j in &tight {
+                a.push(support.iter().map(|&i| matrix[i][j]).collect());
+                b.push(value);
             }
-        }
 
-        true
-    }
+            let Some(weights) = gaussian_elimination(&mut a, &mut b, support.len(), tol) else {
+                continue;
+            };
+            if weights.iter().any(|&w| w < -tol) {
+                continue;
+            }
 
-    /// Calculates expected payoff for given strategies.
-    fn expected_payoff(
-        payoff_matrix: &[Vec<f64>],
-        row_strategy: &[f64],
-        col_strategy: &[f64],
-    ) -> f64 {
-        let mut payoff = 0.0;
-        for (i, row) in payoff_matrix.iter().enumerate() {
-            for (j, &val) in row.iter().enumerate() {
-                payoff += row_strategy[i] * col_strategy[j] * val;
+            let mut strategy = vec![0.0; num_rows];
+            for (&i, &w) in support.iter().zip(&weights) {
+                strategy[i] = w.max(0.0);
+            }
+
+            let guarantees_value = (0..num_cols).all(|j| {
+                (0..num_rows).map(|i| strategy[i] * matrix[i][j]).sum::<f64>()
+                    >= value - tol.sqrt()
+            });
+            let is_new = strategies.iter().all(|s| {
+                s.iter().zip(&strategy).any(|(x, y)| (x - y).abs() > tol.sqrt())
+            });
+
+            if guarantees_value && is_new {
+                strategies.push(strategy);
             }
         }
-        payoff
     }
 
-    /// Returns the full solution details.
-    pub fn solution(&self) -> &GameSolution {
-        &self.solution
-    }
+    strategies
 }
 
 #[cfg(test)]
@@ -113,4 +321,95 @@ mod tests {
             0.01
         ));
     }
+
+    #[test]
+    fn test_find_epsilon_default_data() {
+        let pk = crate::football::penalty::PenaltyKick::with_default_data();
+        let matrix = pk.payoff_matrix().to_expected_payoff();
+
+        let approx = NashEquilibrium::find_epsilon(matrix.clone(), 0.01).unwrap();
+
+        assert!(approx.achieved_epsilon < 0.01);
+        assert!(NashEquilibrium::is_epsilon_nash(
+            &matrix,
+            &approx.solution.row_strategy,
+            &approx.solution.col_strategy,
+            0.01
+        ));
+    }
+
+    #[test]
+    fn test_find_all_degenerate() {
+        // The first two rows are identical, so Row can split its weight on
+        // them in any proportion
+        let matrix = vec![
+            vec![1.0, -1.0],
+            vec![1.0, -1.0],
+            vec![-1.0, 1.0],
+        ];
+
+        let solutions = NashEquilibrium::find_all(matrix.clone()).unwrap();
+
+        assert!(solutions.len() >= 2);
+        assert!(solutions.iter().any(|s| s.row_strategy[0] > 0.49 && s.row_strategy[1] < 0.01));
+        assert!(solutions.iter().any(|s| s.row_strategy[1] > 0.49 && s.row_strategy[0] < 0.01));
+        for solution in &solutions {
+            assert!(NashEquilibrium::is_epsilon_nash(
+                &matrix,
+                &solution.row_strategy,
+                &solution.col_strategy,
+                1e-6
+            ));
+        }
+    }
+
+    #[test]
+    fn test_find_all_unique_equilibrium() {
+        let matrix = vec![
+            vec![1.0, -1.0],
+            vec![-1.0, 1.0],
+        ];
+
+        let solutions = NashEquilibrium::find_all(matrix).unwrap();
+
+        assert_eq!(solutions.len(), 1);
+        assert!((solutions[0].row_strategy[0] - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_empty_matrix() {
+        assert!(!NashEquilibrium::is_epsilon_nash(&[], &[], &[], 1.0));
+        assert!(!NashEquilibrium::is_epsilon_nash(&[vec![]], &[1.0], &[], 1.0));
+        assert!(matches!(NashEquilibrium::find_epsilon(vec![], 0.1), Err(GameError::EmptyMatrix)));
+        assert!(matches!(NashEquilibrium::find_all(vec![vec![]]), Err(GameError::EmptyMatrix)));
+        assert!(extreme_optimal_strategies(&[], 0.0, 1e-9).is_empty());
+    }
+
+    #[test]
+    fn test_find_all_rejects_large_games() {
+        let matrix: Vec<Vec<f64>> = (0..32).map(|i| vec![i as f64, 0.0]).collect();
+
+        assert!(matches!(
+            NashEquilibrium::find_all(matrix),
+            Err(GameError::TooManyActions { actions: 32, limit: 20 })
+        ));
+    }
+
+    #[test]
+    fn test_find_all_with_default_config() {
+        let matrix = vec![
+            vec![1.0, -1.0],
+            vec![1.0, -1.0],
+            vec![-1.0, 1.0],
+        ];
+
+        let solutions = NashEquilibrium::find_all(matrix.clone()).unwrap();
+        let configured = NashEquilibrium::find_all_with_config(matrix, SolverConfig::default()).unwrap();
+
+        assert_eq!(solutions.len(), configured.len());
+        for (a, b) in solutions.iter().zip(&configured) {
+            assert_eq!(a.row_strategy, b.row_strategy);
+            assert_eq!(a.row_support, b.row_support);
+        }
+    }
 }
@@ -0,0 +1,258 @@
+use super::game::{GameError, GameSolver};
+use super::{col_payoffs, row_payoffs};
+use thiserror::Error;
+
+/// Default maximum number of fixed-point iterations.
+const MAX_ITERATIONS: usize = 1_000_000;
+
+/// Default convergence threshold on the fixed-point residual.
+const TOLERANCE: f64 = 1e-10;
+
+#[derive(Error, Debug)]
+pub enum QuantalError {
+    #[error("Invalid payoff matrix: {0}")]
+    InvalidMatrix(#[from] GameError),
+    #[error("Rationality parameter must be finite and non-negative, got {0}")]
+    InvalidLambda(f64),
+}
+
+/// Outcome of an iterative solve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Convergence {
+    /// The residual dropped below the tolerance
+    Converged { iterations: usize, final_gap: f64 },
+    /// The iteration cap was reached first
+    NotConverged { iterations: usize, final_gap: f64 },
+}
+
+impl Convergence {
+    /// Returns true if the solve met its tolerance.
+    pub fn is_converged(&self) -> bool {
+        matches!(self, Convergence::Converged { .. })
+    }
+
+    /// Returns the number of iterations performed.
+    pub fn iterations(&self) -> usize {
+        match *self {
+            Convergence::Converged { iterations, .. } | Convergence::NotConverged { iterations, .. } => iterations,
+        }
+    }
+
+    /// Returns the last residual measured.
+    pub fn final_gap(&self) -> f64 {
+        match *self {
+            Convergence::Converged { final_gap, .. } | Convergence::NotConverged { final_gap, .. } => final_gap,
+        }
+    }
+}
+
+/// Logit quantal response equilibrium of a two-player zero-sum game.
+#[derive(Debug, Clone)]
+pub struct QuantalSolution {
+    /// Row player (maximizer) mixed strategy
+    pub row_strategy: Vec<f64>,
+    /// Column player (minimizer) mixed strategy
+    pub col_strategy: Vec<f64>,
+    /// Expected payoff to the Row player under both strategies
+    pub expected_payoff: f64,
+    /// Rationality parameter the equilibrium was computed for
+    pub lambda: f64,
+    /// Whether the fixed-point iteration met its tolerance
+    pub convergence: Convergence,
+}
+
+/// Logit quantal response equilibrium solver.
+///
+/// Each player chooses action `i` with probability proportional to
+/// `exp(lambda * payoff_i)`, where `payoff_i` is the expected payoff of `i`
+/// against the opponent's mixed strategy. `lambda = 0` gives uniform play and
+/// the equilibrium approaches Nash as `lambda` grows.
+#[derive(Debug, Clone)]
+pub struct QuantalResponse {
+    max_iterations: usize,
+    tolerance: f64,
+}
+
+impl QuantalResponse {
+    /// Creates a solver with the default iteration cap and tolerance.
+    pub fn new() -> Self {
+        Self {
+            max_iterations: MAX_ITERATIONS,
+            tolerance: TOLERANCE,
+        }
+    }
+
+    /// Sets the maximum number of fixed-point iterations.
+    pub fn max_iterations(mut self, max: usize) -> Self {
+        self.max_iterations = max.max(1);
+        self
+    }
+
+    /// Sets the residual below which the iteration counts as converged.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Solves for the logit QRE with rationality parameter `lambda`.
+    ///
+    /// The matrix is from Row player's perspective (Row wants to maximize).
+    /// Uses damped fixed-point iteration; the damping shrinks with
+    /// `lambda` so the iteration stays contractive for sharp responses.
+    /// If the cap is reached first, the last iterate is returned with
+    /// `Convergence::NotConverged`.
+    pub fn solve(&self, matrix: &[Vec<f64>], lambda: f64) -> Result<QuantalSolution, QuantalError> {
+        let solver = GameSolver::new(matrix.to_vec())?;
+
+        if !lambda.is_finite() || lambda < 0.0 {
+            return Err(QuantalError::InvalidLambda(lambda));
+        }
+
+        let num_rows = matrix.len();
+        let num_cols = matrix[0].len();
+
+        let spread = payoff_spread(matrix);
+        let damping = 1.0 / (1.0 + (lambda * spread).powi(2) / 4.0);
+
+        let mut row_strategy = vec![1.0 / num_rows as f64; num_rows];
+        let mut col_strategy = vec![1.0 / num_cols as f64; num_cols];
+
+        let mut residual = f64::INFINITY;
+
+        for iteration in 1..=self.max_iterations {
+            // Column's own payoffs are the negation of Row's
+            let row_values = row_payoffs(matrix, &col_strategy);
+            let col_values: Vec<f64> = col_payoffs(matrix, &row_strategy).iter().map(|v| -v).collect();
+
+            let row_response = logit_response(&row_values, lambda);
+            let col_response = logit_response(&col_values, lambda);
+
+            residual = max_difference(&row_response, &row_strategy)
+                .max(max_difference(&col_response, &col_strategy));
+
+            if residual < self.tolerance {
+                let expected_payoff = solver.expected_payoff(&row_strategy, &col_strategy);
+                return Ok(QuantalSolution {
+                    row_strategy,
+                    col_strategy,
+                    expected_payoff,
+                    lambda,
+                    convergence: Convergence::Converged {
+                        iterations: iteration,
+                        final_gap: residual,
+                    },
+                });
+            }
+
+            blend(&mut row_strategy, &row_response, damping);
+            blend(&mut col_strategy, &col_response, damping);
+        }
+
+        let expected_payoff = solver.expected_payoff(&row_strategy, &col_strategy);
+        Ok(QuantalSolution {
+            row_strategy,
+            col_strategy,
+            expected_payoff,
+            lambda,
+            convergence: Convergence::NotConverged {
+                iterations: self.max_iterations,
+                final_gap: residual,
+            },
+        })
+    }
+}
+
+impl Default for QuantalResponse {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the softmax of `lambda * payoffs`.
+fn logit_response(payoffs: &[f64], lambda: f64) -> Vec<f64> {
+    // Subtract the maximum for numerical stability
+    let max = payoffs.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let weights: Vec<f64> = payoffs.iter().map(|&u| (lambda * (u - max)).exp()).collect();
+    let total: f64 = weights.iter().sum();
+    weights.iter().map(|w| w / total).collect()
+}
+
+/// Moves `current` a fraction `step` of the way toward `target`.
+fn blend(current: &mut [f64], target: &[f64], step: f64) {
+    for (c, &t) in current.iter_mut().zip(target) {
+        *c += step * (t - *c);
+    }
+}
+
+fn max_difference(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| (x - y).abs())
+        .fold(0.0, f64::max)
+}
+
+/// Returns the difference between the largest and smallest payoff.
+fn payoff_spread(matrix: &[Vec<f64>]) -> f64 {
+    let values = matrix.iter().flatten();
+    let max = values.clone().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let min = values.cloned().fold(f64::INFINITY, f64::min);
+    max - min
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    // Asymmetric matching pennies; Nash has both players at (0.4, 0.6)
+    fn pennies() -> Vec<Vec<f64>> {
+        vec![vec![2.0, -1.0], vec![-1.0, 1.0]]
+    }
+
+    #[test]
+    fn test_lambda_zero_is_uniform() {
+        let solution = QuantalResponse::new().solve(&pennies(), 0.0).unwrap();
+
+        for &p in solution.row_strategy.iter().chain(&solution.col_strategy) {
+            assert_relative_eq!(p, 0.5, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_converges_toward_nash() {
+        let nash = GameSolver::new(pennies()).unwrap().solve().unwrap();
+        let distance = |lambda: f64| {
+            let qre = QuantalResponse::new().solve(&pennies(), lambda).unwrap();
+            max_difference(&qre.row_strategy, &nash.row_strategy)
+                .max(max_difference(&qre.col_strategy, &nash.col_strategy))
+        };
+
+        // Small lambdas first pull Row toward its higher-average action, so
+        // the approach to Nash is monotone once responses become sharp
+        let distances: Vec<f64> = [1.0, 2.0, 5.0, 10.0, 50.0].iter().map(|&l| distance(l)).collect();
+
+        assert!(distances.windows(2).all(|w| w[1] < w[0]), "{:?}", distances);
+        assert!(distances[4] < 0.02);
+    }
+
+    #[test]
+    fn test_negative_lambda_rejected() {
+        assert!(matches!(
+            QuantalResponse::new().solve(&pennies(), -1.0),
+            Err(QuantalError::InvalidLambda(_))
+        ));
+    }
+
+    #[test]
+    fn test_convergence_report() {
+        let converged = QuantalResponse::new().solve(&pennies(), 5.0).unwrap();
+        assert!(converged.convergence.is_converged());
+        assert!(converged.convergence.final_gap() < TOLERANCE);
+
+        let capped = QuantalResponse::new().max_iterations(3).solve(&pennies(), 5.0).unwrap();
+        assert!(matches!(
+            capped.convergence,
+            Convergence::NotConverged { iterations: 3, final_gap } if final_gap > TOLERANCE
+        ));
+    }
+}
@@ -1,3 +1,5 @@
+use super::SolverConfig;
+use std::time::Instant;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -10,6 +12,10 @@ pub enum SimplexError {
     InvalidDimensions,
     #[error("Maximum iterations exceeded")]
     MaxIterations,
+    #[error("Deadline exceeded")]
+    Timeout,
+    #[error("Basis is singular or infeasible")]
+    InvalidBasis,
 }
 
 /// Simplex method solver for linear programming problems.
@@ -22,7 +28,11 @@ pub struct Simplex {
     tableau: Vec<Vec<f64>>,
     num_vars: usize,
     num_constraints: usize,
-    max_iterations: usize,
+    config: SolverConfig,
+    deadline: Option<Instant>,
+    /// Basic column for each constraint row
+    basis: Vec<usize>,
+    iterations: usize,
 }
 
 impl Simplex {
@@ -75,13 +85,73 @@ impl Simplex {
             tableau,
             num_vars,
             num_constraints,
-            max_iterations: 1000,
+            config: SolverConfig::default(),
+            deadline: None,
+            basis: (num_vars..num_vars + num_constraints).collect(),
+            iterations: 0,
         })
     }
 
+    /// Creates a solver whose tableau starts from a previously optimal basis.
+    ///
+    /// `basis` lists one column per constraint, as returned by `basis`:
+    /// original variables are `0..c.len()` and the slack of constraint `i`
+    /// is `c.len() + i`. Installing it costs one elimination per column, after
+    /// which `solve` only needs the pivots separating the old optimum from
+    /// the new one. Fails with `InvalidBasis` if the columns are linearly
+    /// dependent or the basic solution is infeasible for `b`, judged with
+    /// the default `SolverConfig` tolerances.
+    pub fn from_basis(c: &[f64], a: &[Vec<f64>], b: &[f64], basis: &[usize]) -> Result<Self, SimplexError> {
+        let mut simplex = Self::new(c, a, b)?;
+        let total_vars = simplex.num_vars + simplex.num_constraints;
+
+        if basis.len() != simplex.num_constraints || basis.iter().any(|&col| col >= total_vars) {
+            return Err(SimplexError::InvalidDimensions);
+        }
+
+        let mut assigned = vec![false; simplex.num_constraints];
+        for &col in basis {
+            // Partial pivoting over the rows not yet holding a basic column
+            let pivot_row = (0..simplex.num_constraints)
+                .filter(|&i| !assigned[i])
+                .max_by(|&i, &k| {
+                    simplex.tableau[i][col].abs().partial_cmp(&simplex.tableau[k][col].abs()).unwrap()
+                })
+                .filter(|&i| simplex.tableau[i][col].abs() > simplex.config.pivot_tol)
+                .ok_or(SimplexError::InvalidBasis)?;
+
+            simplex.pivot(pivot_row, col);
+            assigned[pivot_row] = true;
+        }
+
+        let rhs_col = total_vars;
+        for row in simplex.tableau.iter_mut().take(simplex.num_constraints) {
+            if row[rhs_col] < -simplex.config.zero_tol {
+                return Err(SimplexError::InvalidBasis);
+            }
+            row[rhs_col] = row[rhs_col].max(0.0);
+        }
+
+        Ok(simplex)
+    }
+
     /// Sets the maximum number of iterations.
     pub fn max_iterations(mut self, max: usize) -> Self {
-        self.max_iterations = max;
+        self.config.max_iterations = max;
+        self
+    }
+
+    /// Sets the pivot tolerance and iteration limit.
+    pub fn with_config(mut self, config: SolverConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Aborts `solve` with `SimplexError::Timeout` once `deadline` has passed.
+    ///
+    /// The clock is checked before every pivot.
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
         self
     }
 
@@ -89,7 +159,11 @@ impl Simplex {
     ///
     /// Returns the optimal value and the solution vector.
     pub fn solve(&mut self) -> Result<(f64, Vec<f64>), SimplexError> {
-        for _ in 0..self.max_iterations {
+        for _ in 0..self.config.max_iterations {
+            if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Err(SimplexError::Timeout);
+            }
+
             // Find the pivot column (most negative in objective row)
             let pivot_col = self.find_pivot_column();
 
@@ -105,6 +179,7 @@ impl Simplex {
 
             // Perform pivot operation
             self.pivot(pivot_row, pivot_col);
+            self.iterations += 1;
         }
 
         Err(SimplexError::MaxIterations)
@@ -115,12 +190,13 @@ impl Simplex {
         let obj_row = &self.tableau[self.num_constraints];
         let num_cols = obj_row.len() - 1; // Exclude RHS
 
-        let mut min_val = 0.0;
+        // Rounding can leave reduced costs of an optimal tableau just below zero
+        let mut min_val = -self.config.pivot_tol;
         let mut min_col = None;
 
-        for j in 0..num_cols {
-            if obj_row[j] < min_val {
-                min_val = obj_row[j];
+        for (j, &val) in obj_row.iter().enumerate().take(num_cols) {
+            if val < min_val {
+                min_val = val;
                 min_col = Some(j);
             }
         }
@@ -136,9 +212,10 @@ impl Simplex {
 
         for i in 0..self.num_constraints {
             let coeff = self.tableau[i][pivot_col];
-            if coeff > 1e-10 {
-                let ratio = self.tableau[i][rhs_col] / coeff;
-                if ratio >= 0.0 && ratio < min_ratio {
+            if coeff > self.config.pivot_tol {
+                // A degenerate row's RHS may round slightly below zero
+                let ratio = (self.tableau[i][rhs_col] / coeff).max(0.0);
+                if ratio < min_ratio {
                     min_ratio = ratio;
                     min_row = Some(i);
                 }
@@ -168,45 +245,51 @@ impl Simplex {
                 }
             }
         }
+
+        self.basis[pivot_row] = pivot_col;
     }
 
     /// Extracts the solution from the final tableau.
+    ///
+    /// Reads basic variables from `basis` rather than by spotting unit
+    /// columns, which duplicate constraint columns would make ambiguous.
     fn extract_solution(&self) -> (f64, Vec<f64>) {
+        let (optimal_value, solution, _) = self.full_solution();
+        (optimal_value, solution)
+    }
+
+    /// Returns `(objective, structural variables, slack variables)` for the current basis.
+    ///
+    /// Slack `i` is `b_i - a_i · x`, so it is zero exactly when constraint
+    /// `i` is binding. Call after `solve` to read the optimal solution.
+    pub fn full_solution(&self) -> (f64, Vec<f64>, Vec<f64>) {
         let rhs_col = self.tableau[0].len() - 1;
-        let mut solution = vec![0.0; self.num_vars];
-
-        // Find basic variables
-        for j in 0..self.num_vars {
-            let mut basic_row = None;
-            let mut is_basic = true;
-
-            for i in 0..=self.num_constraints {
-                let val = self.tableau[i][j];
-                if (val - 1.0).abs() < 1e-10 {
-                    if basic_row.is_some() {
-                        is_basic = false;
-                        break;
-                    }
-                    basic_row = Some(i);
-                } else if val.abs() > 1e-10 {
-                    is_basic = false;
-                    break;
-                }
-            }
+        let mut values = vec![0.0; self.num_vars + self.num_constraints];
 
-            if is_basic {
-                if let Some(row) = basic_row {
-                    if row < self.num_constraints {
-                        solution[j] = self.tableau[row][rhs_col];
-                    }
-                }
-            }
+        for (row, &col) in self.basis.iter().enumerate() {
+            values[col] = self.tableau[row][rhs_col];
         }
 
-        // Optimal value is in the bottom-right corner
-        let optimal_value = self.tableau[self.num_constraints][rhs_col];
+        let slacks = values.split_off(self.num_vars);
+        (self.tableau[self.num_constraints][rhs_col], values, slacks)
+    }
 
-        (optimal_value, solution)
+    /// Returns the dual price of each constraint for the current basis.
+    ///
+    /// These are the objective-row entries under the slack columns: how much
+    /// the optimum would grow per unit increase of `b_i`. Call after `solve`.
+    pub fn dual_values(&self) -> Vec<f64> {
+        self.tableau[self.num_constraints][self.num_vars..self.num_vars + self.num_constraints].to_vec()
+    }
+
+    /// Returns the basic column of each constraint row.
+    pub fn basis(&self) -> &[usize] {
+        &self.basis
+    }
+
+    /// Returns the number of pivots performed by `solve`.
+    pub fn iterations(&self) -> usize {
+        self.iterations
     }
 
     /// Returns the current tableau (for debugging).
@@ -240,6 +323,53 @@ mod tests {
         assert_relative_eq!(solution[1], 2.0, epsilon = 1e-6);
     }
 
+    #[test]
+    fn test_full_solution_slacks() {
+        // Same LP as test_simple_maximization; x + y <= 4 and x <= 2 bind at (2, 2)
+        let c = vec![3.0, 2.0];
+        let a = vec![
+            vec![1.0, 1.0],
+            vec![1.0, 0.0],
+            vec![0.0, 1.0],
+        ];
+        let b = vec![4.0, 2.0, 3.0];
+
+        let mut solver = Simplex::new(&c, &a, &b).unwrap();
+        solver.solve().unwrap();
+        let (optimal, x, slacks) = solver.full_solution();
+
+        assert_relative_eq!(optimal, 10.0, epsilon = 1e-6);
+        assert_relative_eq!(x[0], 2.0, epsilon = 1e-6);
+        assert_relative_eq!(x[1], 2.0, epsilon = 1e-6);
+        assert_relative_eq!(slacks[0], 0.0, epsilon = 1e-9);
+        assert_relative_eq!(slacks[1], 0.0, epsilon = 1e-9);
+        assert_relative_eq!(slacks[2], 1.0, epsilon = 1e-6);
+
+        // Dual: min 4u + 2v + 3w s.t. u + v >= 3, u + w >= 2 -> (2, 1, 0)
+        let duals = solver.dual_values();
+        assert_relative_eq!(duals[0], 2.0, epsilon = 1e-9);
+        assert_relative_eq!(duals[1], 1.0, epsilon = 1e-9);
+        assert_relative_eq!(duals[2], 0.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_duplicate_columns() {
+        // x2 and x3 have identical columns, so only one of them may be basic
+        let c = vec![1.0, 1.0, 1.0];
+        let a = vec![vec![2.0, 1.0, 1.0], vec![1.0, 2.0, 2.0]];
+        let b = vec![1.0, 1.0];
+
+        let mut solver = Simplex::new(&c, &a, &b).unwrap();
+        let (optimal, x) = solver.solve().unwrap();
+
+        assert_relative_eq!(optimal, 2.0 / 3.0, epsilon = 1e-9);
+        assert_relative_eq!(x.iter().sum::<f64>(), 2.0 / 3.0, epsilon = 1e-9);
+        for (row, &limit) in a.iter().zip(&b) {
+            let load: f64 = row.iter().zip(&x).map(|(a, x)| a * x).sum();
+            assert!(load <= limit + 1e-9, "{:?} violates {:?}", x, row);
+        }
+    }
+
     #[test]
     fn test_another_lp() {
         // Maximize: 5x + 4y
@@ -259,4 +389,38 @@ mod tests {
         assert_relative_eq!(solution[0], 3.75, epsilon = 1e-6);
         assert_relative_eq!(solution[1], 1.25, epsilon = 1e-6);
     }
+
+    #[test]
+    fn test_warm_start_from_basis() {
+        // Column LP of a shifted 3x3 penalty game
+        let c = vec![1.0; 3];
+        let a = vec![
+            vec![1.16, 1.86, 1.90],
+            vec![1.66, 0.88, 1.66],
+            vec![1.86, 1.80, 1.20],
+        ];
+        let b = vec![1.0; 3];
+
+        let mut original = Simplex::new(&c, &a, &b).unwrap();
+        original.solve().unwrap();
+
+        let mut perturbed = a.clone();
+        perturbed[1][1] += 0.02;
+
+        let mut cold = Simplex::new(&c, &perturbed, &b).unwrap();
+        let (cold_value, cold_solution) = cold.solve().unwrap();
+        let mut warm = Simplex::from_basis(&c, &perturbed, &b, original.basis()).unwrap();
+        let (warm_value, warm_solution) = warm.solve().unwrap();
+
+        assert_relative_eq!(warm_value, cold_value, epsilon = 1e-9);
+        for (w, c) in warm_solution.iter().zip(&cold_solution) {
+            assert_relative_eq!(*w, *c, epsilon = 1e-9);
+        }
+        assert!(warm.iterations() < cold.iterations());
+
+        assert!(matches!(
+            Simplex::from_basis(&c, &a, &b, &[0, 0, 1]),
+            Err(SimplexError::InvalidBasis)
+        ));
+    }
 }
@@ -0,0 +1,36 @@
+//! Helpers for tests and benchmarks, enabled by the `testing` feature.
+
+use crate::analysis::simulation::SimpleRng;
+use crate::football::stats::PkRecord;
+
+/// Generates a `rows` x `cols` matrix of uniform values in `[0, 1)`.
+///
+/// The same seed always yields the same matrix.
+pub fn random_matrix(rows: usize, cols: usize, seed: u64) -> Vec<Vec<f64>> {
+    let mut rng = SimpleRng::new(seed);
+    (0..rows)
+        .map(|_| (0..cols).map(|_| rng.next_f64()).collect())
+        .collect()
+}
+
+/// Builds a record of `goals` from `attempts` for one kick/dive pairing.
+pub fn pk_record(kick: &str, gk: &str, goals: u32, attempts: u32) -> PkRecord {
+    PkRecord {
+        kick_direction: kick.to_string(),
+        gk_direction: gk.to_string(),
+        goals,
+        attempts,
+    }
+}
+
+/// Builds one record per cell of a 3x3 left/center/right grid.
+///
+/// `goals[i][j]` is the goal count for kick `i` against dive `j`, each
+/// from `attempts` kicks.
+pub fn pk_records(goals: [[u32; 3]; 3], attempts: u32) -> Vec<PkRecord> {
+    let dirs = ["left", "center", "right"];
+    dirs.iter()
+        .enumerate()
+        .flat_map(|(i, kick)| dirs.iter().enumerate().map(move |(j, gk)| pk_record(kick, gk, goals[i][j], attempts)))
+        .collect()
+}
@@ -0,0 +1,78 @@
+//! Frame-by-frame ASCII display of iterative solvers converging.
+
+/// Width of a bar for probability 1.
+const BAR_WIDTH: usize = 40;
+
+/// ANSI codes moving the cursor home and clearing the screen.
+const CURSOR_RESET: &str = "\x1b[H\x1b[2J";
+
+/// Renders one frame: the iteration number and a bar per action.
+pub fn render_convergence_frame(strategy: &[f64], iteration: usize) -> String {
+    let mut output = format!("\nIteration {}\n\n", iteration);
+
+    for (i, p) in strategy.iter().enumerate() {
+        let bar_len = (p.clamp(0.0, 1.0) * BAR_WIDTH as f64).round() as usize;
+        output.push_str(&format!(
+            "{:>10} │{:<width$}│ {:>5.1}%\n",
+            format!("Action {}", i + 1),
+            "█".repeat(bar_len),
+            p * 100.0,
+            width = BAR_WIDTH
+        ));
+    }
+
+    output
+}
+
+/// Renders every `step`-th strategy of a trajectory, e.g. from `SelfPlay`.
+///
+/// The final strategy is always included so the animation ends on the
+/// converged state. With `ansi`, each frame starts with a cursor reset so
+/// printing the frames in sequence redraws in place.
+pub fn render_convergence(trajectory: &[Vec<f64>], step: usize, ansi: bool) -> Vec<String> {
+    let step = step.max(1);
+    let last = trajectory.len().saturating_sub(1);
+
+    trajectory
+        .iter()
+        .enumerate()
+        .filter(|&(iteration, _)| iteration % step == 0 || iteration == last)
+        .map(|(iteration, strategy)| {
+            let frame = render_convergence_frame(strategy, iteration);
+            if ansi { format!("{}{}", CURSOR_RESET, frame) } else { frame }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::selfplay::SelfPlay;
+    use crate::football::penalty::PenaltyKick;
+
+    #[test]
+    fn test_first_and_last_frames_differ() {
+        let matrix = PenaltyKick::with_default_data().payoff_matrix().matrix().clone();
+        let result = SelfPlay::run(&matrix, 1000, 0.1, 42).unwrap();
+        let last = result.kicker_trajectory.len() - 1;
+
+        let first_frame = render_convergence_frame(&result.kicker_trajectory[0], 0);
+        let last_frame = render_convergence_frame(&result.kicker_trajectory[last], last);
+
+        assert_ne!(first_frame, last_frame);
+        assert!(first_frame.contains('█') && last_frame.contains('█'));
+        assert!(first_frame.contains("Iteration 0"));
+    }
+
+    #[test]
+    fn test_frame_sequence() {
+        let trajectory = vec![vec![0.5, 0.5], vec![0.6, 0.4], vec![0.7, 0.3], vec![0.65, 0.35]];
+
+        let frames = render_convergence(&trajectory, 2, false);
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[2], render_convergence_frame(&trajectory[3], 3));
+
+        let ansi = render_convergence(&trajectory, 2, true);
+        assert!(ansi.iter().all(|frame| frame.starts_with(CURSOR_RESET)));
+    }
+}
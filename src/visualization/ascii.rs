@@ -1,4 +1,9 @@
-/// ASCII art visualization for penalty kick strategies.
+//! ASCII art visualization for penalty kick strategies.
+
+use std::fmt;
+
+/// Narrowest goal that still fits a "100.0%" label in each section.
+const MIN_WIDTH: usize = 28;
 
 /// Renders a football goal with strategy distribution overlay.
 pub struct GoalVisualizer {
@@ -12,6 +17,16 @@ impl GoalVisualizer {
         }
     }
 
+    /// Creates a visualizer with the given total goal width.
+    ///
+    /// Widths below 28 columns are raised to 28 so each section can hold
+    /// its label, bar and percentage.
+    pub fn with_width(width: usize) -> Self {
+        Self {
+            width: width.max(MIN_WIDTH),
+        }
+    }
+
     /// Renders the goal with kicker's strategy probabilities.
     pub fn render_kicker_strategy(&self, left: f64, center: f64, right: f64) -> String {
         self.render_strategy("KICKER STRATEGY", left, center, right)
@@ -22,7 +37,65 @@ impl GoalVisualizer {
         self.render_strategy("GOALKEEPER STRATEGY", left, center, right)
     }
 
+    /// Renders the kicker's and goalkeeper's goals side by side.
+    pub fn render_matchup(&self, kicker: [f64; 3], gk: [f64; 3]) -> String {
+        let left = self.render_kicker_strategy(kicker[0], kicker[1], kicker[2]);
+        let right = self.render_goalkeeper_strategy(gk[0], gk[1], gk[2]);
+        let left_width = left.lines().map(display_width).max().unwrap_or(0);
+
+        left.lines()
+            .zip(right.lines())
+            .map(|(l, r)| {
+                let padding = " ".repeat(left_width - display_width(l));
+                format!("{}{}{}", l, padding, r).trim_end().to_string() + "\n"
+            })
+            .collect()
+    }
+
+    /// Draws the goal as a TikZ picture annotated with both strategies.
+    ///
+    /// The goal is drawn to scale (7.32m by 2.44m) and split into thirds,
+    /// each labeled with the kicker's and goalkeeper's probability.
+    pub fn to_tikz(&self, kicker: [f64; 3], gk: [f64; 3]) -> String {
+        let mut output = String::from("\\begin{tikzpicture}\n");
+        output.push_str("  \\draw[very thick] (0,0) -- (0,2.44) -- (7.32,2.44) -- (7.32,0);\n");
+        output.push_str("  \\draw[dashed] (2.44,0) -- (2.44,2.44);\n");
+        output.push_str("  \\draw[dashed] (4.88,0) -- (4.88,2.44);\n");
+
+        for (i, name) in ["Left", "Center", "Right"].iter().enumerate() {
+            let x = 1.22 + 2.44 * i as f64;
+            output.push_str(&format!(
+                "  \\node[align=center] at ({:.2},1.22) {{{}\\\\Kick: {:.1}\\%\\\\GK: {:.1}\\%}};\n",
+                x,
+                name,
+                kicker[i] * 100.0,
+                gk[i] * 100.0
+            ));
+        }
+
+        output.push_str("\\end{tikzpicture}\n");
+        output
+    }
+
     fn render_strategy(&self, title: &str, left: f64, center: f64, right: f64) -> String {
+        let mut output = String::new();
+        self.render_to(&mut output, title, left, center, right)
+            .expect("Writing to a String cannot fail");
+        output
+    }
+
+    /// Writes a titled goal diagram for the given probabilities to `w`.
+    ///
+    /// `render_kicker_strategy` and `render_goalkeeper_strategy` produce the
+    /// same text with their fixed titles.
+    pub fn render_to(
+        &self,
+        w: &mut impl fmt::Write,
+        title: &str,
+        left: f64,
+        center: f64,
+        right: f64,
+    ) -> fmt::Result {
         let left_pct = format!("{:.1}%", left * 100.0);
         let center_pct = format!("{:.1}%", center * 100.0);
         let right_pct = format!("{:.1}%", right * 100.0);
@@ -31,9 +104,12 @@ impl GoalVisualizer {
         let center_bar = self.probability_bar(center);
         let right_bar = self.probability_bar(right);
 
-        let section_width = (self.width - 4) / 3;
+        let section_width = self.section_width();
+        // Inner width of the footer, matching the three sections plus dividers
+        let inner_width = section_width * 3 + 2;
 
-        format!(
+        write!(
+            w,
             r#"
     {title:^width$}
     ╔{bar}╦{bar}╦{bar}╗
@@ -42,16 +118,15 @@ impl GoalVisualizer {
     ║{lp:^sw$}║{cp:^sw$}║{rp:^sw$}║
     ║{:^sw$}║{:^sw$}║{:^sw$}║
     ╠{bar}╩{bar}╩{bar}╣
-    ║{:^fw$}║
+    ║{}║
     ╚{fbar}╝
 "#,
             "", "", "",
-            "⚽ GOAL ⚽",
+            center_display("⚽ GOAL ⚽", inner_width),
             title = title,
-            width = self.width,
+            width = inner_width + 2,
             bar = "═".repeat(section_width),
-            fbar = "═".repeat(self.width - 2),
-            fw = self.width - 2,
+            fbar = "═".repeat(inner_width),
             sw = section_width,
             left_sec = "LEFT",
             center_sec = "CENTER",
@@ -65,10 +140,16 @@ impl GoalVisualizer {
         )
     }
 
+    /// Width of each of the three goal sections.
+    fn section_width(&self) -> usize {
+        (self.width.max(MIN_WIDTH) - 4) / 3
+    }
+
     /// Creates a visual bar representing probability.
     fn probability_bar(&self, prob: f64) -> String {
-        let max_blocks = 10;
-        let filled = (prob * max_blocks as f64).round() as usize;
+        // Up to 10 blocks, shrinking to fit narrow sections (2 for the brackets)
+        let max_blocks = (self.section_width() - 2).min(10);
+        let filled = (prob.clamp(0.0, 1.0) * max_blocks as f64).round() as usize;
         let empty = max_blocks - filled;
         format!("[{}{}]", "█".repeat(filled), "░".repeat(empty))
     }
@@ -80,6 +161,26 @@ impl Default for GoalVisualizer {
     }
 }
 
+/// Returns the number of terminal columns a string occupies.
+///
+/// Emoji such as ⚽ render two columns wide; box drawing and block
+/// characters render one column wide.
+fn display_width(s: &str) -> usize {
+    s.chars()
+        .map(|c| match c as u32 {
+            0x26BD | 0x1F300..=0x1FAFF => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Centers `text` within `width` terminal columns.
+fn center_display(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(text));
+    let left = padding / 2;
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(padding - left))
+}
+
 /// Renders a simple pitch diagram showing the PK scenario.
 pub fn render_pitch() -> String {
     r#"
@@ -101,6 +202,25 @@ pub fn render_pitch() -> String {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_render_to_matches_render() {
+        let viz = GoalVisualizer::new();
+        let mut buffer = String::new();
+        viz.render_to(&mut buffer, "KICKER STRATEGY", 0.34, 0.28, 0.38).unwrap();
+
+        assert_eq!(buffer, viz.render_kicker_strategy(0.34, 0.28, 0.38));
+    }
+
+    #[test]
+    fn test_to_tikz() {
+        let tikz = GoalVisualizer::new().to_tikz([0.34, 0.28, 0.38], [0.4, 0.2, 0.4]);
+
+        assert!(tikz.starts_with("\\begin{tikzpicture}"));
+        assert!(tikz.trim_end().ends_with("\\end{tikzpicture}"));
+        assert!(tikz.contains("Left\\\\Kick: 34.0\\%\\\\GK: 40.0\\%"));
+        assert_eq!(tikz.matches("\\node").count(), 3);
+    }
+
     #[test]
     fn test_goal_visualizer() {
         let viz = GoalVisualizer::new();
@@ -111,6 +231,39 @@ mod tests {
         assert!(output.contains("34.0%"));
     }
 
+    #[test]
+    fn test_lines_align_at_any_width() {
+        for width in [30, 90] {
+            let viz = GoalVisualizer::with_width(width);
+            let output = viz.render_goalkeeper_strategy(1.0, 0.0, 0.0);
+            assert!(output.contains("100.0%"));
+
+            let widths: Vec<usize> = output
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(display_width)
+                .collect();
+            assert!(widths.iter().all(|&w| w == widths[0]), "width {}: {:?}", width, widths);
+        }
+    }
+
+    #[test]
+    fn test_matchup_shares_lines() {
+        let viz = GoalVisualizer::new();
+        let output = viz.render_matchup([0.34, 0.28, 0.38], [0.5, 0.1, 0.4]);
+
+        assert!(output.lines().any(|line| line.contains("KICKER") && line.contains("GOALKEEPER")));
+        assert!(output.lines().any(|line| line.contains("34.0%") && line.contains("50.0%")));
+        assert!(output.lines().any(|line| line.matches('╔').count() == 2));
+    }
+
+    #[test]
+    fn test_minimum_width() {
+        let viz = GoalVisualizer::with_width(5);
+        let output = viz.render_kicker_strategy(0.34, 0.28, 0.38);
+        assert!(output.contains("CENTER"));
+    }
+
     #[test]
     fn test_probability_bar() {
         let viz = GoalVisualizer::new();
@@ -1,4 +1,11 @@
-/// ASCII bar chart visualization for strategy comparison.
+//! ASCII bar chart visualization for strategy comparison.
+
+use std::fmt;
+
+#[cfg(feature = "plotters")]
+use super::png::{PngError, IMAGE_SIZE};
+#[cfg(feature = "plotters")]
+use std::path::Path;
 
 /// Horizontal bar chart renderer.
 pub struct BarChart {
@@ -17,42 +24,127 @@ impl BarChart {
     /// Renders a horizontal bar chart comparing values.
     pub fn render(&self, title: &str, data: &[(&str, f64)], max_value: f64) -> String {
         let mut output = String::new();
+        self.render_to(&mut output, title, data, max_value)
+            .expect("Writing to a String cannot fail");
+        output
+    }
 
+    /// Writes the same chart as `render` to `w`.
+    pub fn render_to(
+        &self,
+        w: &mut impl fmt::Write,
+        title: &str,
+        data: &[(&str, f64)],
+        max_value: f64,
+    ) -> fmt::Result {
         // Title
-        output.push_str(&format!("\n{}\n", title));
-        output.push_str(&format!("{}\n\n", "─".repeat(title.len())));
+        writeln!(w, "\n{}", title)?;
+        writeln!(w, "{}\n", "─".repeat(title.len()))?;
 
         for (label, value) in data {
             let bar_len = ((value / max_value) * self.max_bar_width as f64).round() as usize;
             let bar = "█".repeat(bar_len);
             let percentage = format!("{:.1}%", value * 100.0);
 
-            output.push_str(&format!(
-                "{:>width$} │{:<bar_width$}│ {}\n",
+            writeln!(
+                w,
+                "{:>width$} │{:<bar_width$}│ {}",
                 label,
                 bar,
                 percentage,
                 width = self.label_width,
                 bar_width = self.max_bar_width
-            ));
+            )?;
         }
 
         // Scale
-        output.push_str(&format!(
-            "{:>width$} └{}┘\n",
+        writeln!(
+            w,
+            "{:>width$} └{}┘",
             "",
             "─".repeat(self.max_bar_width),
             width = self.label_width
-        ));
-        output.push_str(&format!(
-            "{:>width$}  0%{:^center$}100%\n",
-            "",
+        )?;
+        writeln!(
+            w,
+            "{:>width$}  {}",
             "",
-            width = self.label_width,
-            center = self.max_bar_width - 6
-        ));
+            self.scale_labels(max_value),
+            width = self.label_width
+        )
+    }
 
-        output
+    /// Writes the same chart as `render` to a PNG image, as vertical bars.
+    #[cfg(feature = "plotters")]
+    pub fn render_png(
+        &self,
+        path: impl AsRef<Path>,
+        title: &str,
+        data: &[(&str, f64)],
+        max_value: f64,
+    ) -> Result<(), PngError> {
+        use plotters::prelude::*;
+
+        if data.is_empty() {
+            return Err(PngError::EmptyData);
+        }
+
+        let root = BitMapBackend::new(path.as_ref(), IMAGE_SIZE).into_drawing_area();
+        root.fill(&WHITE)?;
+
+        // Segmenting the inclusive range 0..=n-1 gives one slot per bar
+        let mut chart = ChartBuilder::on(&root)
+            .caption(title, ("sans-serif", 28))
+            .margin(20)
+            .x_label_area_size(40)
+            .y_label_area_size(60)
+            .build_cartesian_2d((0..data.len() - 1).into_segmented(), 0.0..max_value)?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_labels(data.len())
+            .x_label_formatter(&|x| match x {
+                SegmentValue::CenterOf(i) => data.get(*i).map_or(String::new(), |(label, _)| label.to_string()),
+                _ => String::new(),
+            })
+            .y_label_formatter(&|y| percent_label(*y))
+            .draw()?;
+
+        chart.draw_series(
+            Histogram::vertical(&chart)
+                .style(BLUE.mix(0.7).filled())
+                .margin(20)
+                .data(data.iter().enumerate().map(|(i, (_, value))| (i, *value))),
+        )?;
+
+        root.present()?;
+        Ok(())
+    }
+
+    /// Lays out percentage labels at 0, 25, 50, 75 and 100% of `max_value`.
+    ///
+    /// Each label is centered on its tick, the end labels are kept inside the
+    /// axis, and labels that would collide with their left neighbour are
+    /// dropped.
+    fn scale_labels(&self, max_value: f64) -> String {
+        let mut line = String::new();
+
+        for k in 0..=4 {
+            let label = percent_label(max_value * k as f64 / 4.0);
+            let tick = k * self.max_bar_width / 4;
+            let start = tick
+                .saturating_sub(label.len() / 2)
+                .min(self.max_bar_width.saturating_sub(label.len()));
+            let gap = if line.is_empty() { 0 } else { 1 };
+
+            if start >= line.len() + gap || k == 0 {
+                line.push_str(&" ".repeat(start.saturating_sub(line.len())));
+                line.push_str(&label);
+            }
+        }
+
+        line.trim_end().to_string()
     }
 
     /// Renders a comparison chart with two series side by side.
@@ -129,6 +221,108 @@ impl BarChart {
 
         output
     }
+
+    /// Renders a histogram of the samples with `bins` equal-width bins.
+    ///
+    /// The range is taken from the data. Each bin is drawn as a horizontal
+    /// bar scaled to the fullest bin, followed by its count.
+    pub fn render_histogram(&self, title: &str, samples: &[f64], bins: usize) -> String {
+        let mut output = String::new();
+
+        output.push_str(&format!("\n{}\n", title));
+        output.push_str(&format!("{}\n\n", "─".repeat(title.len())));
+
+        let counts = histogram_counts(samples, bins);
+        if counts.is_empty() {
+            return output;
+        }
+
+        let min_val = samples.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max_val = samples.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let bin_width = (max_val - min_val) / counts.len() as f64;
+        let max_count = counts.iter().copied().max().unwrap_or(0).max(1);
+
+        for (i, &count) in counts.iter().enumerate() {
+            let low = min_val + bin_width * i as f64;
+            let high = low + bin_width;
+            let label = format!("{:.2}-{:.2}", low, high);
+            let bar_len = (count as f64 / max_count as f64 * self.max_bar_width as f64).round() as usize;
+
+            output.push_str(&format!(
+                "{:>width$} │{:<bar_width$}│ {}\n",
+                label,
+                "█".repeat(bar_len),
+                count,
+                width = self.label_width,
+                bar_width = self.max_bar_width
+            ));
+        }
+
+        output
+    }
+
+    /// Renders a tornado diagram of sensitivity swings.
+    ///
+    /// Each entry is `(label, low, high)`: the outcome when a parameter is
+    /// swung down and up. Bars extend left and right of a shared baseline
+    /// (the mean midpoint of all entries) and are sorted by total span,
+    /// widest first.
+    pub fn render_tornado(&self, title: &str, entries: &[(&str, f64, f64)]) -> String {
+        let mut output = String::new();
+
+        output.push_str(&format!("\n{}\n", title));
+        output.push_str(&format!("{}\n\n", "─".repeat(title.len())));
+
+        if entries.is_empty() {
+            return output;
+        }
+
+        let mut sorted = entries.to_vec();
+        sorted.sort_by(|a, b| (b.2 - b.1).abs().total_cmp(&(a.2 - a.1).abs()));
+
+        let baseline = entries.iter().map(|(_, low, high)| (low + high) / 2.0).sum::<f64>()
+            / entries.len() as f64;
+        let max_swing = entries
+            .iter()
+            .flat_map(|(_, low, high)| [(low - baseline).abs(), (high - baseline).abs()])
+            .fold(0.0, f64::max);
+        let half_width = self.max_bar_width / 2;
+        let scale = |swing: f64| -> usize {
+            if max_swing < 1e-12 {
+                0
+            } else {
+                (swing / max_swing * half_width as f64).round() as usize
+            }
+        };
+
+        for (label, low, high) in sorted {
+            let left = scale((baseline - low.min(high)).max(0.0));
+            let right = scale((low.max(high) - baseline).max(0.0));
+
+            output.push_str(&format!(
+                "{:>width$} {:>half$}┃{:<half$} {:.3} / {:.3}\n",
+                label,
+                "█".repeat(left),
+                "█".repeat(right),
+                low,
+                high,
+                width = self.label_width,
+                half = half_width
+            ));
+        }
+
+        // Axis marker under the baseline
+        output.push_str(&format!(
+            "{:>width$} {:half$}┴ baseline {:.3}\n",
+            "",
+            "",
+            baseline,
+            width = self.label_width,
+            half = half_width
+        ));
+
+        output
+    }
 }
 
 impl Default for BarChart {
@@ -137,6 +331,138 @@ impl Default for BarChart {
     }
 }
 
+/// ASCII line chart for series that evolve over iterations.
+pub struct LineChart {
+    height: usize,
+    max_width: usize,
+    label_width: usize,
+}
+
+impl LineChart {
+    pub fn new() -> Self {
+        Self {
+            height: 10,
+            max_width: 60,
+            label_width: 8,
+        }
+    }
+
+    /// Renders one or more series against their iteration index.
+    ///
+    /// The y-axis spans the minimum and maximum over all series. Series
+    /// longer than the plot width are sampled evenly. Each series is drawn
+    /// with its own character, listed in the legend.
+    pub fn render(&self, title: &str, series: &[(&str, &[f64])]) -> String {
+        const MARKS: [char; 4] = ['█', '▓', '░', '▒'];
+
+        let mut output = String::new();
+        output.push_str(&format!("\n{}\n", title));
+        output.push_str(&format!("{}\n\n", "─".repeat(title.chars().count())));
+
+        let len = series.iter().map(|(_, values)| values.len()).max().unwrap_or(0);
+        if len == 0 {
+            return output;
+        }
+
+        let values = series.iter().flat_map(|(_, values)| values.iter().copied());
+        let min_val = values.clone().fold(f64::INFINITY, f64::min);
+        let max_val = values.fold(f64::NEG_INFINITY, f64::max);
+        let range = max_val - min_val;
+
+        let width = len.min(self.max_width);
+        let mut grid = vec![vec![' '; width]; self.height];
+
+        for (s, (_, values)) in series.iter().enumerate() {
+            let mark = MARKS[s % MARKS.len()];
+            for (x, column) in (0..width).map(|x| (x, x * (len - 1) / (width - 1).max(1))) {
+                let Some(&v) = values.get(column) else { continue };
+                let level = if range < 1e-10 {
+                    self.height / 2
+                } else {
+                    (((v - min_val) / range) * (self.height - 1) as f64).round() as usize
+                };
+                grid[self.height - 1 - level.min(self.height - 1)][x] = mark;
+            }
+        }
+
+        for (row, cells) in grid.iter().enumerate() {
+            let axis_label = if row == 0 {
+                format!("{:.3}", max_val)
+            } else if row == self.height - 1 {
+                format!("{:.3}", min_val)
+            } else {
+                String::new()
+            };
+            output.push_str(&format!(
+                "{:>width$} ┤{}\n",
+                axis_label,
+                cells.iter().collect::<String>(),
+                width = self.label_width
+            ));
+        }
+
+        // X axis
+        output.push_str(&format!("{:>width$} └{}\n", "", "─".repeat(width), width = self.label_width));
+        let last = (len - 1).to_string();
+        output.push_str(&format!(
+            "{:>width$}  0{}{}\n\n",
+            "",
+            " ".repeat(width.saturating_sub(1 + last.len())),
+            last,
+            width = self.label_width
+        ));
+
+        // Legend
+        for (s, (label, _)) in series.iter().enumerate() {
+            output.push_str(&format!("  {} {}\n", MARKS[s % MARKS.len()], label));
+        }
+
+        output
+    }
+}
+
+impl Default for LineChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Formats a fraction as a percentage, with a decimal only when needed.
+fn percent_label(value: f64) -> String {
+    let pct = value * 100.0;
+    if (pct - pct.round()).abs() < 1e-9 {
+        format!("{:.0}%", pct)
+    } else {
+        format!("{:.1}%", pct)
+    }
+}
+
+/// Buckets samples into `bins` equal-width bins spanning the data range.
+///
+/// The maximum value falls into the last bin. If all samples are equal,
+/// they all land in the first bin.
+pub fn histogram_counts(samples: &[f64], bins: usize) -> Vec<usize> {
+    if samples.is_empty() || bins == 0 {
+        return Vec::new();
+    }
+
+    let min_val = samples.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max_val = samples.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let range = max_val - min_val;
+
+    let mut counts = vec![0; bins];
+    for &v in samples {
+        let index = if range < 1e-10 {
+            0
+        } else {
+            (((v - min_val) / range) * bins as f64) as usize
+        };
+        counts[index.min(bins - 1)] += 1;
+    }
+
+    counts
+}
+
 /// Creates a sparkline-style mini chart.
 pub fn sparkline(values: &[f64]) -> String {
     const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
@@ -181,6 +507,44 @@ mod tests {
         assert!(output.contains("34.0%"));
     }
 
+    #[test]
+    fn test_render_to_matches_render() {
+        let chart = BarChart::new();
+        let data = vec![("Left", 0.34), ("Center", 0.28), ("Right", 0.38)];
+
+        let mut buffer = String::new();
+        chart.render_to(&mut buffer, "Kicker Strategy", &data, 1.0).unwrap();
+
+        assert_eq!(buffer, chart.render("Kicker Strategy", &data, 1.0));
+    }
+
+    #[test]
+    fn test_scale_follows_max_value() {
+        let chart = BarChart::new();
+        let output = chart.render("Half Scale", &[("A", 0.25)], 0.5);
+        let scale = output.lines().last().unwrap();
+
+        assert!(scale.trim_end().ends_with("50%"), "{}", scale);
+        assert!(!scale.contains("100%"));
+        assert!(scale.contains("12.5%") && scale.contains("25%") && scale.contains("37.5%"));
+
+        let unit = chart.render("Unit Scale", &[("A", 0.25)], 1.0);
+        assert!(unit.lines().last().unwrap().ends_with("100%"));
+    }
+
+    #[cfg(feature = "plotters")]
+    #[test]
+    fn test_render_png() {
+        let path = std::env::temp_dir().join(format!("fgt_chart_{}.png", std::process::id()));
+        let data = vec![("Left", 0.34), ("Center", 0.28), ("Right", 0.38)];
+
+        BarChart::new().render_png(&path, "Kicker Strategy", &data, 1.0).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(bytes.starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+
     #[test]
     fn test_sparkline() {
         let values = vec![0.1, 0.5, 0.3, 0.9, 0.2];
@@ -188,6 +552,61 @@ mod tests {
         assert_eq!(spark.chars().count(), 5);
     }
 
+    #[test]
+    fn test_line_chart() {
+        let chart = LineChart::new();
+        let rising: Vec<f64> = (0..20).map(|i| 0.5 - 0.2 / (i + 1) as f64).collect();
+        let falling: Vec<f64> = (0..20).map(|i| 0.5 + 0.2 / (i + 1) as f64).collect();
+
+        let output = chart.render("Convergence", &[("Left", &rising), ("Right", &falling)]);
+        assert!(output.contains("█ Left"));
+        assert!(output.contains("▓ Right"));
+        assert!(output.contains("0.700"));
+        assert!(output.contains("0.300"));
+        assert!(output.contains("19"));
+    }
+
+    #[test]
+    fn test_histogram() {
+        let samples = vec![0.0, 1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 3.0, 5.0, 5.0];
+
+        let counts = histogram_counts(&samples, 10);
+        assert_eq!(counts.iter().sum::<usize>(), samples.len());
+        assert_eq!(counts, vec![1, 0, 1, 0, 2, 0, 4, 0, 0, 2]);
+
+        let chart = BarChart::new();
+        let output = chart.render_histogram("Goals out of 5", &samples, 10);
+        let bar_lengths: Vec<usize> = output
+            .lines()
+            .filter(|line| line.contains('│'))
+            .map(|line| line.matches('█').count())
+            .collect();
+        assert_eq!(bar_lengths.len(), 10);
+        assert_eq!(bar_lengths.iter().max(), Some(&bar_lengths[6]));
+        assert_eq!(bar_lengths[1], 0);
+    }
+
+    #[test]
+    fn test_tornado() {
+        let chart = BarChart::new();
+        let entries = [("Small", 0.75, 0.85), ("Large", 0.70, 0.90)];
+
+        let output = chart.render_tornado("Sensitivity", &entries);
+        let rows: Vec<&str> = output.lines().filter(|line| line.contains('┃')).collect();
+
+        // Widest swing first
+        assert!(rows[0].contains("Large"));
+        assert!(rows[1].contains("Small"));
+
+        // Symmetric swings around the baseline give equal bars on each side
+        for row in &rows {
+            let (left, right) = row.split_once('┃').unwrap();
+            assert_eq!(left.matches('█').count(), right.matches('█').count());
+        }
+        assert_eq!(rows[0].matches('█').count(), 40);
+        assert_eq!(rows[1].matches('█').count(), 20);
+    }
+
     #[test]
     fn test_distribution() {
         let chart = BarChart::new();
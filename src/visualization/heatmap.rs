@@ -1,4 +1,14 @@
-/// ASCII heatmap visualization for payoff matrices.
+//! ASCII heatmap visualization for payoff matrices.
+
+use crate::football::penalty::SUPPORT_THRESHOLD;
+use std::fmt;
+#[cfg(feature = "plotters")]
+use super::png::{PngError, IMAGE_SIZE};
+#[cfg(feature = "plotters")]
+use std::path::Path;
+
+/// Marker for cells where both players' strategies are in the support.
+const SUPPORT_MARKER: &str = "◆";
 
 /// Color/shade levels for heatmap cells.
 const HEAT_LEVELS: [&str; 10] = [
@@ -6,14 +16,91 @@ const HEAT_LEVELS: [&str; 10] = [
     "▒▓▓", "▓▓▓", "▓▓█", "▓██", "███"
 ];
 
+/// Viridis anchor colors at evenly spaced positions from 0 to 1.
+const VIRIDIS: [(u8, u8, u8); 5] = [
+    (0x44, 0x01, 0x54),
+    (0x3B, 0x52, 0x8B),
+    (0x21, 0x91, 0x8C),
+    (0x5E, 0xC9, 0x62),
+    (0xFD, 0xE7, 0x25),
+];
+
+/// Color scale for the ANSI heatmap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Palette {
+    /// Perceptually uniform and color-blind safe, from `#440154` to `#FDE725`
+    #[default]
+    Viridis,
+    /// Black to white
+    Grayscale,
+}
+
+impl Palette {
+    /// Maps a normalized value in [0, 1] to an RGB color.
+    ///
+    /// Values outside the range are clamped.
+    pub fn color(&self, t: f64) -> (u8, u8, u8) {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Palette::Viridis => {
+                let position = t * (VIRIDIS.len() - 1) as f64;
+                let lower = (position.floor() as usize).min(VIRIDIS.len() - 2);
+                let frac = position - lower as f64;
+                let (a, b) = (VIRIDIS[lower], VIRIDIS[lower + 1]);
+                let mix = |x: u8, y: u8| (x as f64 + (y as f64 - x as f64) * frac).round() as u8;
+                (mix(a.0, b.0), mix(a.1, b.1), mix(a.2, b.2))
+            }
+            Palette::Grayscale => {
+                let level = (t * 255.0).round() as u8;
+                (level, level, level)
+            }
+        }
+    }
+}
+
 /// Renders a payoff matrix as an ASCII heatmap.
 pub struct HeatmapRenderer {
     cell_width: usize,
+    decimals: usize,
+    as_percentage: bool,
+    palette: Palette,
 }
 
 impl HeatmapRenderer {
     pub fn new() -> Self {
-        Self { cell_width: 12 }
+        Self {
+            cell_width: 12,
+            decimals: 2,
+            as_percentage: false,
+            palette: Palette::default(),
+        }
+    }
+
+    /// Sets the color palette used by `render_ansi`.
+    ///
+    /// The block-character renderers stay monochrome.
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Sets the width of each cell (and of the row label column).
+    pub fn cell_width(mut self, width: usize) -> Self {
+        self.cell_width = width;
+        self
+    }
+
+    /// Sets the number of decimal places shown for values.
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Shows values as percentages (`58%`) instead of fractions (`0.58`).
+    pub fn as_percentage(mut self, enabled: bool) -> Self {
+        self.as_percentage = enabled;
+        self
     }
 
     /// Renders the payoff matrix as a heatmap with color gradient.
@@ -25,6 +112,143 @@ impl HeatmapRenderer {
         title: &str,
     ) -> String {
         let mut output = String::new();
+        self.render_to(&mut output, matrix, row_labels, col_labels, title)
+            .expect("Writing to a String cannot fail");
+        output
+    }
+
+    /// Writes the same heatmap as `render` to `w`.
+    pub fn render_to(
+        &self,
+        w: &mut impl fmt::Write,
+        matrix: &[Vec<f64>],
+        row_labels: &[&str],
+        col_labels: &[&str],
+        title: &str,
+    ) -> fmt::Result {
+        w.write_str(&self.render_header(matrix, col_labels, title))?;
+        let (min_val, max_val) = self.find_range(matrix);
+
+        for (i, row) in matrix.iter().enumerate() {
+            w.write_str(&self.render_row(row, row_labels.get(i).unwrap_or(&""), min_val, max_val))?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders the heatmap with cells shaded by 24-bit ANSI background colors.
+    ///
+    /// Cell colors come from the configured palette; text switches between
+    /// black and white to stay readable on light and dark cells.
+    pub fn render_ansi(
+        &self,
+        matrix: &[Vec<f64>],
+        row_labels: &[&str],
+        col_labels: &[&str],
+        title: &str,
+    ) -> String {
+        let mut output = self.render_header(matrix, col_labels, title);
+        let (min_val, max_val) = self.find_range(matrix);
+        let span = max_val - min_val;
+
+        for (i, row) in matrix.iter().enumerate() {
+            output.push_str(&format!("{:>width$}", row_labels.get(i).unwrap_or(&""), width = self.cell_width));
+
+            for &val in row {
+                let t = if span.abs() < 1e-10 { 0.5 } else { (val - min_val) / span };
+                let (r, g, b) = self.palette.color(t);
+                let luminance = 0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64;
+                let text = if luminance > 128.0 { "30" } else { "97" };
+                let cell = format!("{:^width$}", self.format_value(val), width = self.cell_width);
+                output.push_str(&format!(" \x1b[48;2;{};{};{}m\x1b[{}m{}\x1b[0m", r, g, b, text, cell));
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Writes the heatmap to a PNG image, coloring cells with the palette.
+    ///
+    /// Row 0 is drawn at the top, matching the text renderers.
+    #[cfg(feature = "plotters")]
+    pub fn render_png(
+        &self,
+        path: impl AsRef<Path>,
+        matrix: &[Vec<f64>],
+        row_labels: &[&str],
+        col_labels: &[&str],
+        title: &str,
+    ) -> Result<(), PngError> {
+        use plotters::prelude::*;
+        use plotters::style::text_anchor::{HPos, Pos, VPos};
+
+        let num_rows = matrix.len();
+        let num_cols = matrix.first().map_or(0, |row| row.len());
+        if num_rows == 0 || num_cols == 0 {
+            return Err(PngError::EmptyData);
+        }
+
+        let (min_val, max_val) = self.find_range(matrix);
+        let span = max_val - min_val;
+
+        let root = BitMapBackend::new(path.as_ref(), IMAGE_SIZE).into_drawing_area();
+        root.fill(&WHITE)?;
+
+        // Segmenting the inclusive range 0..=n-1 gives one slot per cell
+        let mut chart = ChartBuilder::on(&root)
+            .caption(title, ("sans-serif", 28))
+            .margin(20)
+            .x_label_area_size(40)
+            .y_label_area_size(110)
+            .build_cartesian_2d((0..num_cols - 1).into_segmented(), (0..num_rows - 1).into_segmented())?;
+
+        let label = |labels: &[&str], index: &SegmentValue<usize>, flip: bool| match index {
+            SegmentValue::CenterOf(i) if *i < labels.len() => {
+                labels[if flip { labels.len() - 1 - i } else { *i }].to_string()
+            }
+            _ => String::new(),
+        };
+        chart
+            .configure_mesh()
+            .disable_mesh()
+            .x_labels(num_cols)
+            .y_labels(num_rows)
+            .x_label_formatter(&|x| label(col_labels, x, false))
+            .y_label_formatter(&|y| label(row_labels, y, true))
+            .draw()?;
+
+        let centered = Pos::new(HPos::Center, VPos::Center);
+        for (i, row) in matrix.iter().enumerate() {
+            let y = num_rows - 1 - i;
+            for (j, &val) in row.iter().enumerate() {
+                let t = if span.abs() < 1e-10 { 0.5 } else { (val - min_val) / span };
+                let (r, g, b) = self.palette.color(t);
+                let luminance = 0.299 * r as f64 + 0.587 * g as f64 + 0.114 * b as f64;
+                let text_color = if luminance > 128.0 { BLACK } else { WHITE };
+
+                chart.draw_series(std::iter::once(Rectangle::new(
+                    [
+                        (SegmentValue::Exact(j), SegmentValue::Exact(y)),
+                        (SegmentValue::Exact(j + 1), SegmentValue::Exact(y + 1)),
+                    ],
+                    RGBColor(r, g, b).filled(),
+                )))?;
+                chart.draw_series(std::iter::once(Text::new(
+                    self.format_value(val),
+                    (SegmentValue::CenterOf(j), SegmentValue::CenterOf(y)),
+                    ("sans-serif", 22).into_font().color(&text_color).pos(centered),
+                )))?;
+            }
+        }
+
+        root.present()?;
+        Ok(())
+    }
+
+    /// Renders the title, legend, column headers and separator.
+    fn render_header(&self, matrix: &[Vec<f64>], col_labels: &[&str], title: &str) -> String {
+        let mut output = String::new();
 
         // Find min/max for normalization
         let (min_val, max_val) = self.find_range(matrix);
@@ -48,14 +272,51 @@ impl HeatmapRenderer {
         }
         output.push('\n');
 
-        // Data rows
+        output
+    }
+
+    /// Renders one data row of shaded values.
+    fn render_row(&self, row: &[f64], row_label: &str, min_val: f64, max_val: f64) -> String {
+        let mut output = format!("{:>width$}", row_label, width = self.cell_width);
+
+        for &val in row {
+            let heat = self.value_to_heat(val, min_val, max_val);
+            let cell = format!("{} {}", heat, self.format_value(val));
+            output.push_str(&format!(" {:^width$}", cell, width = self.cell_width));
+        }
+        output.push('\n');
+
+        output
+    }
+
+    /// Renders the heatmap with equilibrium play frequencies overlaid.
+    ///
+    /// Below each row of values is a line with the joint probability
+    /// `row_strategy[i] * col_strategy[j]` of each cell being played. Cells
+    /// where both strategies are in the support are marked with `◆`.
+    pub fn render_with_strategies(
+        &self,
+        matrix: &[Vec<f64>],
+        row_strategy: &[f64],
+        col_strategy: &[f64],
+        row_labels: &[&str],
+        col_labels: &[&str],
+        title: &str,
+    ) -> String {
+        let mut output = self.render_header(matrix, col_labels, title);
+        let (min_val, max_val) = self.find_range(matrix);
+
         for (i, row) in matrix.iter().enumerate() {
-            let row_label = row_labels.get(i).unwrap_or(&"");
-            output.push_str(&format!("{:>width$}", row_label, width = self.cell_width));
+            output.push_str(&self.render_row(row, row_labels.get(i).unwrap_or(&""), min_val, max_val));
 
-            for &val in row {
-                let heat = self.value_to_heat(val, min_val, max_val);
-                let cell = format!("{} {:.2}", heat, val);
+            // Joint play probability of each cell
+            let row_prob = row_strategy.get(i).copied().unwrap_or(0.0);
+            output.push_str(&format!("{:>width$}", "", width = self.cell_width));
+            for j in 0..row.len() {
+                let col_prob = col_strategy.get(j).copied().unwrap_or(0.0);
+                let in_support = row_prob > SUPPORT_THRESHOLD && col_prob > SUPPORT_THRESHOLD;
+                let marker = if in_support { SUPPORT_MARKER } else { " " };
+                let cell = format!("{} {:.1}%", marker, row_prob * col_prob * 100.0);
                 output.push_str(&format!(" {:^width$}", cell, width = self.cell_width));
             }
             output.push('\n');
@@ -127,16 +388,25 @@ impl HeatmapRenderer {
 
     fn render_legend(&self, min_val: f64, max_val: f64) -> String {
         format!(
-            "Low ({:.2}) {} {} {} {} {} High ({:.2})",
-            min_val,
+            "Low ({}) {} {} {} {} {} High ({})",
+            self.format_value(min_val),
             HEAT_LEVELS[0],
             HEAT_LEVELS[2],
             HEAT_LEVELS[5],
             HEAT_LEVELS[7],
             HEAT_LEVELS[9],
-            max_val
+            self.format_value(max_val)
         )
     }
+
+    /// Formats a value using the configured precision and style.
+    fn format_value(&self, val: f64) -> String {
+        if self.as_percentage {
+            format!("{:.*}%", self.decimals, val * 100.0)
+        } else {
+            format!("{:.*}", self.decimals, val)
+        }
+    }
 }
 
 impl Default for HeatmapRenderer {
@@ -165,6 +435,93 @@ mod tests {
         assert!(output.contains("Kick L"));
     }
 
+    #[test]
+    fn test_render_to_matches_render() {
+        let renderer = HeatmapRenderer::new();
+        let matrix = vec![vec![0.58, 0.93], vec![0.83, 0.44]];
+        let rows = vec!["Kick L", "Kick R"];
+        let cols = vec!["GK Left", "GK Right"];
+
+        let mut buffer = String::new();
+        renderer.render_to(&mut buffer, &matrix, &rows, &cols, "PK").unwrap();
+
+        assert_eq!(buffer, renderer.render(&matrix, &rows, &cols, "PK"));
+    }
+
+    #[test]
+    fn test_configured_width_and_format() {
+        let renderer = HeatmapRenderer::new()
+            .cell_width(16)
+            .decimals(0)
+            .as_percentage(true);
+        let matrix = vec![vec![0.58, 0.93], vec![0.83, 0.44]];
+        let rows = vec!["Kick Left", "Kick Center"];
+        let cols = vec!["GK Left Post", "GK Center Stay"];
+
+        let output = renderer.render(&matrix, &rows, &cols, "Rates");
+        for label in rows.iter().chain(cols.iter()) {
+            assert!(output.contains(label));
+        }
+        assert!(output.contains("58%"));
+        assert!(!output.contains('.'));
+
+        // Separators span the configured width
+        assert!(output.contains(&"─".repeat(16)));
+        assert!(!output.contains(&"─".repeat(17)));
+    }
+
+    #[test]
+    fn test_render_with_strategies() {
+        let renderer = HeatmapRenderer::new();
+        let matrix = vec![vec![0.58, 0.93], vec![0.83, 0.44]];
+        let rows = vec!["Kick L", "Kick C"];
+        let cols = vec!["GK L", "GK C"];
+
+        // Only the top-right cell is ever played
+        let output = renderer.render_with_strategies(&matrix, &[1.0, 0.0], &[0.0, 1.0], &rows, &cols, "Play");
+        let lines: Vec<&str> = output.lines().collect();
+        let joint_lines: Vec<&str> = lines
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i > 0 && lines[i - 1].contains("Kick"))
+            .map(|(_, line)| *line)
+            .collect();
+
+        assert_eq!(joint_lines.len(), 2);
+        assert!(joint_lines[0].contains("◆ 100.0%"));
+        assert_eq!(joint_lines[0].matches('◆').count(), 1);
+        assert!(joint_lines[1].contains("0.0%"));
+        assert!(!joint_lines[1].contains('◆'));
+    }
+
+    #[test]
+    fn test_palette_endpoints() {
+        assert_eq!(Palette::Viridis.color(0.0), (0x44, 0x01, 0x54));
+        assert_eq!(Palette::Viridis.color(1.0), (0xFD, 0xE7, 0x25));
+        assert_eq!(Palette::Grayscale.color(0.0), (0, 0, 0));
+        assert_eq!(Palette::Grayscale.color(1.0), (255, 255, 255));
+
+        let renderer = HeatmapRenderer::new().palette(Palette::Grayscale);
+        let output = renderer.render_ansi(&[vec![0.2, 0.8]], &["Kick"], &["L", "R"], "Rates");
+        assert!(output.contains("\x1b[48;2;0;0;0m"));
+        assert!(output.contains("\x1b[48;2;255;255;255m"));
+    }
+
+    #[cfg(feature = "plotters")]
+    #[test]
+    fn test_render_png() {
+        let path = std::env::temp_dir().join(format!("fgt_heatmap_{}.png", std::process::id()));
+        let matrix = vec![vec![0.58, 0.93, 0.95], vec![0.83, 0.44, 0.83], vec![0.93, 0.90, 0.60]];
+
+        HeatmapRenderer::new()
+            .render_png(&path, &matrix, &["L", "C", "R"], &["L", "C", "R"], "Success Rates")
+            .unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(bytes.starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+
     #[test]
     fn test_heat_levels() {
         let renderer = HeatmapRenderer::new();
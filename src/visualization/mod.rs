@@ -1,3 +1,6 @@
+pub mod animate;
 pub mod ascii;
 pub mod heatmap;
 pub mod chart;
+#[cfg(feature = "plotters")]
+pub mod png;
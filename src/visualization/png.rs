@@ -0,0 +1,21 @@
+//! Shared pieces of the `plotters` PNG export.
+
+use plotters::drawing::DrawingAreaErrorKind;
+use thiserror::Error;
+
+/// Pixel size of exported images.
+pub(crate) const IMAGE_SIZE: (u32, u32) = (800, 600);
+
+#[derive(Error, Debug)]
+pub enum PngError {
+    #[error("Nothing to draw")]
+    EmptyData,
+    #[error("Drawing failed: {0}")]
+    Drawing(String),
+}
+
+impl<E: std::error::Error + Send + Sync> From<DrawingAreaErrorKind<E>> for PngError {
+    fn from(e: DrawingAreaErrorKind<E>) -> Self {
+        PngError::Drawing(e.to_string())
+    }
+}
@@ -0,0 +1,76 @@
+use std::process::Command;
+
+fn run(args: &[&str]) -> (bool, String) {
+    let (success, stdout, _) = run_with_stderr(args);
+    (success, stdout)
+}
+
+fn run_with_stderr(args: &[&str]) -> (bool, String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_football-game-theory"))
+        .args(args)
+        .output()
+        .expect("failed to run binary");
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+#[test]
+fn test_analyze_csv_prints_goal_probability() {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/fixture.csv");
+    let (success, stdout) = run(&["analyze", "--csv", fixture]);
+
+    assert!(success);
+    assert!(stdout.contains("Game Value:"), "{}", stdout);
+    assert!(stdout.contains("% expected goal rate"), "{}", stdout);
+}
+
+#[test]
+fn test_missing_csv_fails() {
+    let (success, _) = run(&["analyze", "--csv", "does-not-exist.csv"]);
+
+    assert!(!success);
+}
+
+#[test]
+fn test_invalid_records_fail_with_reason() {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/too_many_goals.csv");
+
+    for command in ["analyze", "simulate"] {
+        let (success, _, stderr) = run_with_stderr(&[command, "--csv", fixture]);
+
+        assert!(!success, "{} exited successfully", command);
+        assert!(stderr.contains("has 144 goals from 100 attempts"), "{}", stderr);
+    }
+}
+
+#[test]
+fn test_simulate_csv_succeeds() {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/fixture.csv");
+    let (success, stdout) = run(&["simulate", "--csv", fixture, "--kicks", "100"]);
+
+    assert!(success);
+    assert!(stdout.contains("Simulated 100 penalty kicks"), "{}", stdout);
+}
+
+#[test]
+fn test_sensitivity_rejects_invalid_delta() {
+    for delta in ["NaN", "inf", "0", "-0.05"] {
+        let arg = format!("--delta={}", delta);
+        let (success, _, stderr) = run_with_stderr(&["sensitivity", &arg]);
+
+        assert!(!success, "delta {} exited successfully", delta);
+        assert!(stderr.contains("delta must be a positive finite number"), "{}", stderr);
+    }
+}
+
+#[test]
+fn test_sensitivity_propagates_load_failure() {
+    let fixture = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/too_many_goals.csv");
+    let (success, _, stderr) = run_with_stderr(&["sensitivity", "--csv", fixture]);
+
+    assert!(!success);
+    assert!(stderr.contains("has 144 goals from 100 attempts"), "{}", stderr);
+}